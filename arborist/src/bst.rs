@@ -1,43 +1,77 @@
 use arborist_proc::{Length, length_method};
 use arborist_core::fenwick::{
     InsertableCollection, IndexedCollection, IndexedCollectionMut,
-    StatefulTreeView, FenwickTreeError, Length, root
+    StatefulTreeView, StatefulTreeViewMut, FenwickTreeError, WalkerPath,
+    Length, Index, root
 };
 use arborist_core::{
-    TreeRead, TreeReadMut, TreeWrite,
-    TreeWalker, Height, Direction, NodeSide,
+    TreeRead, TreeReadMut, TreeReadOrdered, TreeWrite,
+    TreeRank, TreeRankWrite, NodeKey, Identity,
+    Compare, Natural,
+    TreeWalker, TreeWalkerMut, Height, Direction, NodeSide,
     require, unwrap_enum
 };
-use arborist_core::tree_kv::NodeKV;
 
+use core::borrow::Borrow;
 use core::cmp::Ordering;
+use core::marker::PhantomData;
+
+pub mod algebra;
+pub mod bulk;
+pub mod cursor;
+pub mod drain;
+pub mod entry;
+pub mod iter;
+pub mod multi;
+pub mod queue;
+pub mod split;
+
+pub use algebra::{Union, Intersection, Difference, SymmetricDifference};
+pub use bulk::Dedup;
+pub use cursor::{Cursor, CursorMut};
+pub use drain::Drain;
+pub use entry::{Entry, OccupiedEntry, VacantEntry};
+pub use iter::IntoIter;
+pub use queue::{BoundedBST, BoundPolicy, Largest, Smallest};
 
 #[cfg(feature = "bumpalo_vec")]
 pub mod bumpalo_vec {
     use bumpalo::collections::Vec;
-    use super::{BST, NodeKV};
+    use arborist_core::{EntryKey, Identity, Natural};
+    use super::{BST, Multi};
 
-    pub type BSTSet<T> = BST<Vec<T>>;
-    pub type BSTMap<'a, K, V> = BST<Vec<NodeKV<'a, K, V>>>;
+    pub type BSTSet<T, O = Natural> = BST<Vec<T>, usize, Identity, O>;
+    pub type BSTMap<K, V, O = Natural> = BST<Vec<(K, V)>, usize, EntryKey, O>;
+    pub type BSTMultiSet<T, O = Natural> = BST<Vec<T>, usize, Identity, O, Multi>;
+    pub type BSTMultiMap<K, V, O = Natural> = BST<Vec<(K, V)>, usize, EntryKey, O, Multi>;
 }
 
 #[cfg(feature = "std_vec")]
 pub mod std_vec {
     use std::vec::Vec;
-    use arborist_core::fenwick::IndexedCollection;
+    use core::marker::PhantomData;
+    use arborist_core::fenwick::{IndexedCollection, Index};
+    use arborist_core::{EntryKey, Identity, Natural};
 
-    use super::{BST, NodeKV};
+    use super::{BST, Multi};
 
-    pub type BSTSet<T> = BST<Vec<T>>;
-    pub type BSTMap<'t, K, V> = BST<Vec<NodeKV<'t, K, V>>>;
+    pub type BSTSet<T, O = Natural> = BST<Vec<T>, usize, Identity, O>;
+    pub type BSTMap<K, V, O = Natural> = BST<Vec<(K, V)>, usize, EntryKey, O>;
+    pub type BSTMultiSet<T, O = Natural> = BST<Vec<T>, usize, Identity, O, Multi>;
+    pub type BSTMultiMap<K, V, O = Natural> = BST<Vec<(K, V)>, usize, EntryKey, O, Multi>;
 
-    impl<C> From<C> for BST<Vec<C::Output>> where
+    impl<C, I, N, O, P> From<C> for BST<Vec<C::Output>, I, N, O, P> where
         C: IndexedCollection + Into<Vec<C::Output>> + ?Sized,
-        C::Output: Sized
+        C::Output: Sized,
+        I: Index
     {
         fn from(inner: C) -> Self {
             Self {
-                inner: inner.into()
+                inner: inner.into(),
+                index: PhantomData,
+                key: PhantomData,
+                order: PhantomData,
+                policy: PhantomData
             }
         }
     }
@@ -46,31 +80,35 @@ pub mod std_vec {
 #[cfg(feature = "const_vec")]
 pub mod const_vec {
     use tinyvec::{Array, ArrayVec};
+    use arborist_core::{EntryKey, Identity, Natural};
     use super::{
-        BST, NodeKV,
-        IndexedCollection
+        BST, Multi, PhantomData,
+        IndexedCollection, Index
     };
 
-    pub type BSTSetConst<T, const N: usize> = BST<ArrayVec<[T; N]>>;
-    pub type BSTMapConst<'t, K, V, const N: usize> = BST<ArrayVec<[NodeKV<'t, K, V>; N]>>;
+    pub type BSTSetConst<T, const N: usize, O = Natural> = BST<ArrayVec<[T; N]>, usize, Identity, O>;
+    pub type BSTMapConst<K, V, const N: usize, O = Natural> = BST<ArrayVec<[(K, V); N]>, usize, EntryKey, O>;
+    pub type BSTMultiSetConst<T, const N: usize, O = Natural> = BST<ArrayVec<[T; N]>, usize, Identity, O, Multi>;
+    pub type BSTMultiMapConst<K, V, const N: usize, O = Natural> = BST<ArrayVec<[(K, V); N]>, usize, EntryKey, O, Multi>;
 
-    impl<I: Array + IndexedCollection> From<I> for BST<ArrayVec<I>> {
-        fn from(value: I) -> Self {
-            Self { inner: ArrayVec::from(value) }
+    impl<A: Array + IndexedCollection, I: Index, N, O, P> From<A> for BST<ArrayVec<A>, I, N, O, P> {
+        fn from(value: A) -> Self {
+            Self { inner: ArrayVec::from(value), index: PhantomData, key: PhantomData, order: PhantomData, policy: PhantomData }
         }
     }
 }
 
 pub mod bstmap {
     pub use arborist_core::tree_kv::*;
+    pub use super::{Entry, OccupiedEntry, VacantEntry, Dedup, BoundedBST, Largest, Smallest, InsertError};
     pub use arborist_core::fenwick::traits::*;
     pub use arborist_core::Height;
 
     #[cfg(all(feature = "bumpalo_vec", not(feature = "std_vec")))]
-    pub use super::bumpalo_vec::BSTMap;
+    pub use super::bumpalo_vec::{BSTMap, BSTMultiMap};
     
     #[cfg(all(feature = "std_vec", not(feature = "bumpalo_vec")))]
-    pub use super::std_vec::BSTMap;
+    pub use super::std_vec::{BSTMap, BSTMultiMap};
     
     #[cfg(feature = "const_vec")]
     pub use super::const_vec::*;
@@ -78,26 +116,75 @@ pub mod bstmap {
 
 pub mod bstset {
     pub use arborist_core::tree::*;
+    pub use super::{Dedup, BoundedBST, Largest, Smallest, InsertError};
     pub use arborist_core::fenwick::traits::*;
     pub use arborist_core::Height;
 
     #[cfg(all(feature = "bumpalo_vec", not(feature = "std_vec")))]
-    pub use super::bumpalo_vec::BSTSet;
+    pub use super::bumpalo_vec::{BSTSet, BSTMultiSet};
     
     #[cfg(all(feature = "std_vec", not(feature = "bumpalo_vec")))]
-    pub use super::std_vec::BSTSet;
+    pub use super::std_vec::{BSTSet, BSTMultiSet};
     
     #[cfg(feature = "const_vec")]
     pub use super::const_vec::*;
 }
 
+// `I` sets the index width used while walking the tree - collections
+// longer than the width allows are rejected with an overflow error.
+// `N` picks the key nodes are ordered by, the whole node for sets,
+// `O` the order those keys are compared in, and `P` whether equal
+// keys replace each other or sit side by side
 #[derive(Length, Clone, Copy)]
 #[length_method(self.inner.length() - 1)]
-pub struct BST<C: Length> {
-    pub(crate) inner: C
+pub struct BST<C: Length, I: Index = usize, N = Identity, O = Natural, P = Unique> {
+    pub(crate) inner: C,
+    index: PhantomData<I>,
+    key: PhantomData<N>,
+    order: PhantomData<O>,
+    policy: PhantomData<P>
 }
 
-impl<C> BST<C> where C: InsertableCollection {
+pub trait DuplicatePolicy {
+    const DUPLICATES: bool;
+}
+
+// Inserting an equal key replaces the node holding it
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Unique;
+
+// Equal keys are kept adjacent, in insertion order
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Multi;
+
+impl DuplicatePolicy for Unique {
+    const DUPLICATES: bool = false;
+}
+
+impl DuplicatePolicy for Multi {
+    const DUPLICATES: bool = true;
+}
+
+// Compares a borrowed key against the key of each node it meets,
+// so searching never needs a whole node to compare with
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct KeyOrder<N, O = Natural>(PhantomData<(N, O)>);
+
+impl<Q, T, N, O> Compare<Q, T> for KeyOrder<N, O> where
+    N: NodeKey<T>,
+    N::Key: Borrow<Q>,
+    O: Compare<Q>,
+    Q: ?Sized
+{
+    fn compare(key: &Q, node: &T) -> Option<Ordering> {
+        O::compare(key, N::key(node).borrow())
+    }
+}
+
+impl<C, I, N, O, P> BST<C, I, N, O, P> where
+    C: InsertableCollection,
+    I: Index
+{
     pub fn new() -> Self {
         // InsertableCollection::new() ensures collections
         // always have at least 1 cap at compile time
@@ -107,7 +194,11 @@ impl<C> BST<C> where C: InsertableCollection {
         inner.set_length(1);
 
         Self {
-            inner: inner
+            inner: inner,
+            index: PhantomData,
+            key: PhantomData,
+            order: PhantomData,
+            policy: PhantomData
         }
     }
 
@@ -120,61 +211,230 @@ impl<C> BST<C> where C: InsertableCollection {
     }
 }
 
-impl<C> BST<C> where
-    C: InsertableCollection,
-    C::Output: PartialEq + PartialOrd + Sized
+impl<C, I, N, O, P> BST<C, I, N, O, P> where
+    C: IndexedCollection,
+    C::Output: Sized,
+    I: Index
 {
-    pub(crate) fn allocate(&self, node: &impl PartialOrd<C::Output>) -> Result<BSTWalkerResult, BSTError> {
-        let mut walker: BSTWalker<C> = BSTWalker::new(&self.inner)?;
-        Ok(walker.allocate(node))
+    pub(crate) fn allocate<Q>(&self, key: &Q) -> Result<BSTWalkerResult<I>, BSTError<I>> where
+        N: NodeKey<C::Output>,
+        N::Key: Borrow<Q>,
+        O: Compare<Q>,
+        Q: ?Sized
+    {
+        let mut walker: BSTWalker<C, I, KeyOrder<N, O>> = BSTWalker::new(&self.inner)?;
+        walker.allocate(key)
+    }
+
+    pub(crate) fn allocate_past<Q>(&self, key: &Q, side: NodeSide) -> Result<BSTWalkerResult<I>, BSTError<I>> where
+        N: NodeKey<C::Output>,
+        N::Key: Borrow<Q>,
+        O: Compare<Q>,
+        Q: ?Sized
+    {
+        let mut walker: BSTWalker<C, I, KeyOrder<N, O>> = BSTWalker::new(&self.inner)?;
+        walker.allocate_past(key, side)
+    }
+
+    // Duplicate keys resolve to the first - earliest inserted - of their run
+    pub(crate) fn find<Q>(&self, key: &Q) -> Result<usize, BSTError<I>> where
+        N: NodeKey<C::Output>,
+        N::Key: Borrow<Q>,
+        O: Compare<Q>,
+        P: DuplicatePolicy,
+        Q: ?Sized
+    {
+        if !P::DUPLICATES {
+            let mut walker: BSTWalker<C, I, KeyOrder<N, O>> = BSTWalker::new(&self.inner)?;
+            return Ok(walker.find(key)?.as_usize());
+        }
+
+        let index: usize = self.bound_position(key, false)?;
+        require!(
+            index <= self.length() && O::compare(key, N::key(&self.inner[index]).borrow()) == Some(Ordering::Equal),
+            BSTError::KeyNotFound
+        );
+
+        Ok(index)
+    }
+
+    // Slot of the first element not less than `key` - or greater
+    // than it, when `past` is set
+    pub(crate) fn bound_position<Q>(&self, key: &Q, past: bool) -> Result<usize, BSTError<I>> where
+        N: NodeKey<C::Output>,
+        N::Key: Borrow<Q>,
+        O: Compare<Q>,
+        Q: ?Sized
+    {
+        let side: NodeSide = match past {
+            true => NodeSide::Right,
+            false => NodeSide::Left
+        };
+
+        Ok(usize::from(self.allocate_past(key, side)?).min(self.length() + 1))
+    }
+
+    fn neighbour(&self, position: usize) -> Result<&C::Output, BSTError<I>> {
+        require!(position != 0 && position <= self.length(), BSTError::KeyNotFound);
+
+        Ok(&self.inner[position])
     }
 }
 
-impl<C> TreeRead for BST<C> where
+impl<C, I, N, O, P> TreeRead for BST<C, I, N, O, P> where
     C: IndexedCollection,
-    C::Output: Sized + PartialEq + PartialOrd
+    C::Output: Sized,
+    I: Index,
+    N: NodeKey<C::Output>,
+    O: Compare<N::Key>,
+    P: DuplicatePolicy
 {
     type Node = C::Output;
-    type Error = BSTError;
+    type Key = N::Key;
+    type Order = O;
+    type Error = BSTError<I>;
 
-    fn get(&self, node: &C::Output) -> Result<&C::Output, BSTError> {
-        let mut walker: BSTWalker<C> = BSTWalker::new(&self.inner)?;
-        let index: usize = walker.find(node)?;
-        Ok(&self.inner[index])
+    fn get<Q: ?Sized>(&self, key: &Q) -> Result<&C::Output, BSTError<I>> where
+        N::Key: Borrow<Q>,
+        O: Compare<Q>
+    {
+        Ok(&self.inner[self.find(key)?])
     }
 
-    fn first(&self) -> Result<&C::Output, BSTError> {
+    fn first(&self) -> Result<&C::Output, BSTError<I>> {
         require!(self.length() != 0, BSTError::EMPTY);
 
         Ok(&self.inner[1])
     }
 
-    fn last(&self) -> Result<&C::Output, BSTError> {
+    fn last(&self) -> Result<&C::Output, BSTError<I>> {
         require!(self.length() != 0, BSTError::EMPTY);
 
         Ok(&self.inner[self.length()])
     }
 
-    fn root(&self) -> Result<&C::Output, BSTError> {
+    fn root(&self) -> Result<&C::Output, BSTError<I>> {
         require!(self.length() != 0, BSTError::EMPTY);
 
         Ok(&self.inner[root(&self.inner.height())])
     }
 
-    fn contains(&self, node: &C::Output) -> Result<bool, BSTError> {
-        let mut walker: BSTWalker<C> = BSTWalker::new(&self.inner)?;
-        Ok(walker.find(node).is_ok())
+    fn contains<Q: ?Sized>(&self, key: &Q) -> Result<bool, BSTError<I>> where
+        N::Key: Borrow<Q>,
+        O: Compare<Q>
+    {
+        match self.find(key) {
+            Ok(_) => Ok(true),
+            Err(BSTError::KeyNotFound) => Ok(false),
+            Err(err) => Err(err)
+        }
+    }
+}
+
+impl<C, I, N, O, P> TreeReadOrdered for BST<C, I, N, O, P> where
+    C: IndexedCollection,
+    C::Output: Sized,
+    I: Index,
+    N: NodeKey<C::Output>,
+    O: Compare<N::Key>,
+    P: DuplicatePolicy
+{
+    fn floor<Q: ?Sized>(&self, key: &Q) -> Result<&C::Output, BSTError<I>> where
+        N::Key: Borrow<Q>,
+        O: Compare<Q>
+    {
+        self.neighbour(self.bound_position(key, true)? - 1)
+    }
+
+    fn ceiling<Q: ?Sized>(&self, key: &Q) -> Result<&C::Output, BSTError<I>> where
+        N::Key: Borrow<Q>,
+        O: Compare<Q>
+    {
+        self.neighbour(self.bound_position(key, false)?)
+    }
+
+    fn lower<Q: ?Sized>(&self, key: &Q) -> Result<&C::Output, BSTError<I>> where
+        N::Key: Borrow<Q>,
+        O: Compare<Q>
+    {
+        self.neighbour(self.bound_position(key, false)? - 1)
+    }
+
+    fn higher<Q: ?Sized>(&self, key: &Q) -> Result<&C::Output, BSTError<I>> where
+        N::Key: Borrow<Q>,
+        O: Compare<Q>
+    {
+        self.neighbour(self.bound_position(key, true)?)
+    }
+}
+
+impl<C, I, N, O, P> TreeRank for BST<C, I, N, O, P> where
+    C: InsertableCollection,
+    C::Output: Sized,
+    I: Index,
+    N: NodeKey<C::Output>,
+    O: Compare<N::Key>,
+    P: DuplicatePolicy
+{
+    fn rank<Q: ?Sized>(&self, key: &Q) -> Result<usize, BSTError<I>> where
+        N::Key: Borrow<Q>,
+        O: Compare<Q>
+    {
+        Ok(self.bound_position(key, false)? - 1)
+    }
+
+    fn index_of<Q: ?Sized>(&self, key: &Q) -> Result<Option<usize>, BSTError<I>> where
+        N::Key: Borrow<Q>,
+        O: Compare<Q>
+    {
+        match self.find(key) {
+            Ok(index) => Ok(Some(index - 1)),
+            Err(BSTError::KeyNotFound) => Ok(None),
+            Err(err) => Err(err)
+        }
+    }
+}
+
+impl<C, I, N, O, P> TreeRankWrite for BST<C, I, N, O, P> where
+    C: InsertableCollection,
+    C::Output: Sized,
+    I: Index,
+    N: NodeKey<C::Output>,
+    O: Compare<N::Key>,
+    P: DuplicatePolicy
+{
+    fn remove_index(&mut self, n: usize) -> Option<C::Output> {
+        (n < self.length()).then(|| self.inner.remove(n + 1))
     }
 }
 
-impl<C> TreeReadMut for BST<C> where
+impl<C, I, N, O, P> TreeReadMut for BST<C, I, N, O, P> where
     C: IndexedCollectionMut,
-    C::Output: Sized + PartialEq + PartialOrd
+    C::Output: Sized,
+    I: Index,
+    N: NodeKey<C::Output>,
+    O: Compare<N::Key>,
+    P: DuplicatePolicy
 {
-    fn get_mut(&mut self, node: &C::Output) -> Result<&mut C::Output, BSTError> {
-        let mut walker: BSTWalker<C> = BSTWalker::new(&self.inner)?;
-        let index: usize = walker.find(node)?;
-        Ok(&mut self.inner[index])
+    fn get_mut<Q: ?Sized>(&mut self, key: &Q) -> Result<&mut C::Output, BSTError<I>> where
+        N::Key: Borrow<Q>,
+        O: Compare<Q>
+    {
+        let length: usize = self.length();
+        let mut walker: BSTWalkerMut<C, I, KeyOrder<N, O>> = BSTWalkerMut::new(&mut self.inner)?;
+
+        // Land before the whole run of equal keys, so duplicates
+        // resolve to the first of them - as in `find`
+        let index: usize = usize::from(walker.allocate_past(key, NodeSide::Left)?);
+        require!(index <= length, BSTError::KeyNotFound);
+
+        walker.view.seek(WalkerPath::Index(I::from_usize(index).ok_or(BSTError::KeyNotFound)?));
+        require!(
+            walker.view.current().is_ok_and(|node| O::compare(key, N::key(node).borrow()) == Some(Ordering::Equal)),
+            BSTError::KeyNotFound
+        );
+
+        walker.into_current_mut()
     }
 
     fn first_mut(&mut self) -> Result<&mut Self::Node, Self::Error> {
@@ -198,134 +458,260 @@ impl<C> TreeReadMut for BST<C> where
     }
 }
 
-impl<C> TreeWrite for BST<C> where
-    C: InsertableCollection + IndexedCollectionMut,
-    C::Output: Sized + PartialEq + PartialOrd
+impl<C, I, N, O, P> BST<C, I, N, O, P> where
+    C: InsertableCollection,
+    C::Output: Sized,
+    I: Index,
+    N: NodeKey<C::Output>,
+    O: Compare<N::Key>,
+    P: DuplicatePolicy
 {
-    fn insert(&mut self, node: C::Output) -> Result<Option<C::Output>, BSTError> {
-        Ok(match self.allocate(&node)? {
-            BSTWalkerResult::Existing(index) => {
-                Some(core::mem::replace(&mut self.inner[index], node))
+    // As `insert`, but a node that can't go in is handed back with the error
+    pub fn try_insert(&mut self, node: C::Output) -> Result<Option<C::Output>, InsertError<C::Output, I>> {
+        let allocated: Result<BSTWalkerResult<I>, BSTError<I>> = match P::DUPLICATES {
+            // Equal keys queue up behind those already inserted
+            true => self.allocate_past(N::key(&node), NodeSide::Right),
+            false => self.allocate(N::key(&node))
+        };
+
+        Ok(match allocated {
+            Ok(BSTWalkerResult::Existing(index)) => {
+                Some(core::mem::replace(&mut self.inner[index.as_usize()], node))
             },
-            BSTWalkerResult::New(index, side) => {
+            Ok(BSTWalkerResult::New(index, side)) => {
                 // Inserting always requires capacity - it always expands the array one to the right
-                require!(self.inner.has_capacity(), BSTError::FULL);
+                require!(self.inner.length() < I::MAX.as_usize(), InsertError::new(BSTError::Inner(FenwickTreeError::Overflow), node));
+                require!(self.inner.has_capacity(), InsertError::new(BSTError::FULL, node));
 
-                self.inner.insert(index + side as usize, node);
+                self.inner.insert(index.as_usize() + side as usize, node);
                 None
-            }
+            },
+            Err(kind) => return Err(InsertError::new(kind, node))
         })
     }
+}
+
+impl<C, I, N, O, P> TreeWrite for BST<C, I, N, O, P> where
+    C: InsertableCollection + IndexedCollectionMut,
+    C::Output: Sized,
+    I: Index,
+    N: NodeKey<C::Output>,
+    O: Compare<N::Key>,
+    P: DuplicatePolicy
+{
+    fn insert(&mut self, node: C::Output) -> Result<Option<C::Output>, BSTError<I>> {
+        Ok(self.try_insert(node)?)
+    }
 
-    fn delete(&mut self, node: &C::Output) -> Result<C::Output, BSTError> {
-        let mut walker: BSTWalker<C> = BSTWalker::new(&self.inner)?;
-        let index: usize = walker.find(node)?;
+    fn delete<Q: ?Sized>(&mut self, key: &Q) -> Result<C::Output, BSTError<I>> where
+        N::Key: Borrow<Q>,
+        O: Compare<Q>
+    {
+        let index: usize = self.find(key)?;
 
         Ok(self.inner.remove(index))
     }
 
-    fn pop(&mut self) -> Result<C::Output, BSTError> {
+    fn pop(&mut self) -> Result<C::Output, BSTError<I>> {
         require!(self.length() != 0, BSTError::EMPTY);
 
         Ok(self.inner.remove(self.length()))
     }
 }
 
+macro_rules! impl_bst_walker {
+    ($walker:ident, $view:ident, $bound:ident $(, $mut:tt)?) => {
+        impl<'w, C, I, O> $walker<'w, C, I, O> where
+            C: $bound,
+            C::Output: Sized,
+            I: Index
+        {
+            pub fn new(inner: &'w $($mut)? C) -> Result<Self, BSTError<I>> {
+                // Every slot must be addressable, not just the root
+                require!(inner.length() <= I::MAX.as_usize(), BSTError::Inner(FenwickTreeError::Overflow));
+
+                // Start at centermost point of tree
+                let start_index: usize = root(&inner.height());
+
+                Ok(Self {
+                    view: $view::new(inner, I::from_usize(start_index).unwrap_or(I::MAX))?,
+                    order: PhantomData
+                })
+            }
+
+            // Keys the comparator can't order against a node (e.g. NaN) are rejected
+            pub fn allocate<Q: ?Sized>(&mut self, key: &Q) -> Result<BSTWalkerResult<I>, BSTError<I>> where
+                O: Compare<Q, C::Output>
+            {
+                self.descend(key, None)
+            }
+
+            // Walks past nodes equal to `key` onto `side`, landing before the
+            // first of them (Left) or after the last (Right) - never `Existing`
+            pub fn allocate_past<Q: ?Sized>(&mut self, key: &Q, side: NodeSide) -> Result<BSTWalkerResult<I>, BSTError<I>> where
+                O: Compare<Q, C::Output>
+            {
+                self.descend(key, Some(side))
+            }
+
+            fn descend<Q: ?Sized>(&mut self, key: &Q, past: Option<NodeSide>) -> Result<BSTWalkerResult<I>, BSTError<I>> where
+                O: Compare<Q, C::Output>
+            {
+                while self.view.lsb() > I::ONE {
+                    unwrap_enum!(
+                        self.view.current(),
+                        self.view.traverse(Direction::Down(NodeSide::Left)),
+                        Ok(node) => unwrap_enum!(
+                            (O::compare(key, node), past),
+                            return Err(BSTError::Incomparable),
+                            (Some(Ordering::Greater), _) => self.view.traverse(Direction::Down(NodeSide::Right)),
+                            (Some(Ordering::Less), _) => self.view.traverse(Direction::Down(NodeSide::Left)),
+                            (Some(Ordering::Equal), Some(side)) => self.view.traverse(Direction::Down(side)),
+                            (Some(Ordering::Equal), None) => return Ok(BSTWalkerResult::Existing(self.view.index()))
+                        )
+                    );
+                }
+
+                Ok(unwrap_enum!(
+                    self.view.current(),
+                    BSTWalkerResult::New(self.view.index(), NodeSide::Left),
+                    Ok(node) => {
+                        unwrap_enum!(
+                            (O::compare(key, node), past),
+                            return Err(BSTError::Incomparable),
+                            (Some(Ordering::Greater), _) => BSTWalkerResult::New(self.view.index(), NodeSide::Right),
+                            (Some(Ordering::Less), _) => BSTWalkerResult::New(self.view.index(), NodeSide::Left),
+                            (Some(Ordering::Equal), Some(side)) => BSTWalkerResult::New(self.view.index(), side),
+                            (Some(Ordering::Equal), None) => BSTWalkerResult::Existing(self.view.index())
+                        )
+                    }
+                ))
+            }
+
+            pub fn find<Q: ?Sized>(&mut self, key: &Q) -> Result<I, BSTError<I>> where
+                O: Compare<Q, C::Output>
+            {
+                match self.allocate(key)? {
+                    BSTWalkerResult::Existing(index) => Ok(index),
+                    _ => Err(BSTError::KeyNotFound)
+                }
+            }
+
+            pub fn reset(&mut self) {
+                let start_index: usize = root(&self.view.height());
+                self.view.seek(WalkerPath::Index(I::from_usize(start_index).unwrap_or(I::MAX)))
+            }
+        }
+    };
+}
+
+// `O` compares the keys walked with against the nodes they meet
 #[derive(Debug, Length)]
 #[length_method(self.view.length())]
-pub struct BSTWalker<'w, C: IndexedCollection> {
-    pub view: StatefulTreeView<'w, C>
+pub struct BSTWalker<'w, C: IndexedCollection, I: Index = usize, O = Natural> {
+    pub view: StatefulTreeView<'w, C, I>,
+    order: PhantomData<O>
 }
 
-impl<'w, C> BSTWalker<'w, C> where
-    C: IndexedCollection,
-    C::Output: PartialOrd + Sized
-{
-    pub fn new(inner: &'w C) -> Result<Self, BSTError> {
-        // Start at centermost point of tree
-        let start_index: usize = root(&inner.height());
+// Walks like `BSTWalker`, but hands out mutable access to the nodes it
+// lands on - values may change in place, keys must keep their order
+#[derive(Debug, Length)]
+#[length_method(self.view.length())]
+pub struct BSTWalkerMut<'w, C: IndexedCollectionMut, I: Index = usize, O = Natural> {
+    pub view: StatefulTreeViewMut<'w, C, I>,
+    order: PhantomData<O>
+}
 
-        Ok(Self {
-            view: StatefulTreeView::new(inner, start_index)?
-        })
+impl_bst_walker!{BSTWalker, StatefulTreeView, IndexedCollection}
+impl_bst_walker!{BSTWalkerMut, StatefulTreeViewMut, IndexedCollectionMut, mut}
+
+impl<'w, C, I, O> BSTWalkerMut<'w, C, I, O> where
+    C: IndexedCollectionMut,
+    C::Output: Sized,
+    I: Index
+{
+    pub fn current_mut(&mut self) -> Result<&mut C::Output, BSTError<I>> {
+        Ok(self.view.current_mut()?)
     }
 
-    pub fn allocate(&mut self, key: &impl PartialOrd<C::Output>) -> BSTWalkerResult {
-        while self.view.lsb() > 1 {
-            unwrap_enum!(
-                self.view.current(),
-                self.view.traverse(Direction::Down(NodeSide::Left)),
-                Ok(node) => unwrap_enum!(
-                    key.partial_cmp(node),
-                    panic!("Invariant: PartialCmp failed to return a value"),
-                    Some(Ordering::Greater) => self.view.traverse(Direction::Down(NodeSide::Right)),
-                    Some(Ordering::Less) => self.view.traverse(Direction::Down(NodeSide::Left)),
-                    Some(Ordering::Equal) => return BSTWalkerResult::Existing(self.view.index())
-                )
-            );
-        }
+    pub fn find_mut<Q: ?Sized>(&mut self, key: &Q) -> Result<&mut C::Output, BSTError<I>> where
+        O: Compare<Q, C::Output>
+    {
+        self.find(key)?;
+        self.current_mut()
+    }
 
-        unwrap_enum!(
-            self.view.current(),
-            BSTWalkerResult::New(self.view.index(), NodeSide::Left),
-            Ok(node) => {
-                unwrap_enum!(
-                    key.partial_cmp(node),
-                    panic!("Invariant: PartialCmp failed to return a value"),
-                    Some(Ordering::Greater) => BSTWalkerResult::New(self.view.index(), NodeSide::Right),
-                    Some(Ordering::Less) => BSTWalkerResult::New(self.view.index(), NodeSide::Left),
-                    Some(Ordering::Equal) => BSTWalkerResult::Existing(self.view.index())
-                )
-            }
-        )
+    // Steps to the in-order successor/predecessor, erroring past either end
+    pub fn next_mut(&mut self) -> Result<&mut C::Output, BSTError<I>> {
+        self.view.traverse(Direction::Next);
+        self.current_mut()
     }
 
-    pub fn find(&mut self, key: &impl PartialOrd<C::Output>) -> Result<usize, BSTError> {
-        match self.allocate(key) {
-            BSTWalkerResult::Existing(index) => Ok(index),
-            _ => Err(BSTError::KeyNotFound)
-        }
+    pub fn prev_mut(&mut self) -> Result<&mut C::Output, BSTError<I>> {
+        self.view.traverse(Direction::Prev);
+        self.current_mut()
     }
 
-    pub fn reset(&mut self) {
-        self.view.seek(root(&self.view.height()))
+    pub fn into_current_mut(self) -> Result<&'w mut C::Output, BSTError<I>> {
+        Ok(self.view.into_current_mut()?)
     }
 }
 
 #[derive(Debug, Clone, PartialEq)]
-pub enum BSTWalkerResult {
-    New(usize, NodeSide),
-    Existing(usize)
+pub enum BSTWalkerResult<I = usize> {
+    New(I, NodeSide),
+    Existing(I)
 }
 
-impl From<BSTWalkerResult> for usize {
-    fn from(value: BSTWalkerResult) -> usize {
-        // (Discriminant(0|1), NodeSide(0|1), index)
-        let raw: &(u8, u8, usize) = unsafe {
-            core::mem::transmute::<&BSTWalkerResult, &(u8, u8, usize)>(&value)
-        };
-        
-        // Round the index up/down appropriately, according to the side the match
-        // fell on (Right/Left). Select for such using bitmask based on the Discriminant:
-        // New = 0, Existing = 1. Existing = ignore value as it'll be garbage
-        // (whatever's read from memory at that location)
-        (raw.1 & (1 ^ raw.0)) as usize + raw.2
+impl<I: Index> From<BSTWalkerResult<I>> for usize {
+    fn from(value: BSTWalkerResult<I>) -> usize {
+        // Round the index up/down appropriately, according to
+        // the side the match fell on (Right/Left)
+        match value {
+            BSTWalkerResult::New(index, side) => index.as_usize() + side as usize,
+            BSTWalkerResult::Existing(index) => index.as_usize()
+        }
     }
 }
 
 #[derive(Debug, Clone, PartialEq)]
-pub enum BSTError {
+pub enum BSTError<I = usize> {
     KeyNotFound,
-    Inner(FenwickTreeError)
+    Unordered,
+    Incomparable,
+    Inner(FenwickTreeError<I>)
 }
 
-impl BSTError {
+impl<I> BSTError<I> {
     pub const EMPTY: Self = BSTError::Inner(FenwickTreeError::Empty);
     pub const FULL: Self = BSTError::Inner(FenwickTreeError::Full);
 }
 
-impl From<FenwickTreeError> for BSTError {
-    fn from(err: FenwickTreeError) -> Self {
+// A failed insert, holding on to the value it was given
+#[derive(Debug, Clone, PartialEq)]
+pub struct InsertError<T, I = usize> {
+    pub kind: BSTError<I>,
+    pub value: T
+}
+
+impl<T, I> InsertError<T, I> {
+    pub fn new(kind: BSTError<I>, value: T) -> Self {
+        Self { kind, value }
+    }
+
+    pub fn into_value(self) -> T {
+        self.value
+    }
+}
+
+impl<T, I> From<InsertError<T, I>> for BSTError<I> {
+    fn from(err: InsertError<T, I>) -> Self {
+        err.kind
+    }
+}
+
+impl<I> From<FenwickTreeError<I>> for BSTError<I> {
+    fn from(err: FenwickTreeError<I>) -> Self {
         Self::Inner(err)
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,137 @@
+use arborist_core::fenwick::{InsertableCollection, Index};
+use arborist_core::{TreeIter, Identity, Compare};
+
+use super::BST;
+
+use core::cmp::Ordering;
+use core::iter::{FusedIterator, Peekable};
+use core::marker::PhantomData;
+use core::slice;
+
+// Both sets are already sorted, so every operation is a single lazy
+// merge of the two - O(n + m), whatever the backends on either side
+
+struct MergeIter<'a, T, O> {
+    left: Peekable<slice::Iter<'a, T>>,
+    right: Peekable<slice::Iter<'a, T>>,
+    order: PhantomData<O>
+}
+
+impl<'a, T, O: Compare<T>> MergeIter<'a, T, O> {
+    fn new(left: &'a [T], right: &'a [T]) -> Self {
+        Self {
+            left: left.iter().peekable(),
+            right: right.iter().peekable(),
+            order: PhantomData
+        }
+    }
+
+    // Steps past the least remaining element, on whichever side(s) hold it.
+    // Incomparable pairs are treated as distinct, left first
+    fn next_pair(&mut self) -> Option<(Option<&'a T>, Option<&'a T>)> {
+        let ordering: Option<Ordering> = match (self.left.peek(), self.right.peek()) {
+            (None, None) => return None,
+            (Some(_), None) => Some(Ordering::Less),
+            (None, Some(_)) => Some(Ordering::Greater),
+            (Some(left), Some(right)) => O::compare(left, right)
+        };
+
+        Some(match ordering {
+            Some(Ordering::Greater) => (None, self.right.next()),
+            Some(Ordering::Equal) => (self.left.next(), self.right.next()),
+            _ => (self.left.next(), None)
+        })
+    }
+}
+
+macro_rules! impl_set_op {
+    ($name:ident, | $left:ident, $right:ident | $item:expr) => {
+        pub struct $name<'a, T, O> {
+            inner: MergeIter<'a, T, O>
+        }
+
+        impl<'a, T, O: Compare<T>> Iterator for $name<'a, T, O> {
+            type Item = &'a T;
+
+            fn next(&mut self) -> Option<&'a T> {
+                while let Some(($left, $right)) = self.inner.next_pair() {
+                    if let Some(item) = $item {
+                        return Some(item);
+                    }
+                }
+
+                None
+            }
+        }
+
+        impl<'a, T, O: Compare<T>> FusedIterator for $name<'a, T, O> {}
+    };
+}
+
+impl_set_op!{Union, | left, right | left.or(right)}
+impl_set_op!{Intersection, | left, right | left.and(right)}
+impl_set_op!{Difference, | left, right | left.filter(| _ | right.is_none())}
+impl_set_op!{SymmetricDifference, | left, right | left.xor(right)}
+
+impl<C, I, O> BST<C, I, Identity, O> where
+    C: InsertableCollection,
+    C::Output: Sized,
+    I: Index,
+    O: Compare<C::Output>
+{
+    fn merge_iter<'a, C2, I2>(&'a self, other: &'a BST<C2, I2, Identity, O>) -> MergeIter<'a, C::Output, O> where
+        C2: InsertableCollection<Output = C::Output>,
+        I2: Index
+    {
+        MergeIter::new(TreeIter::as_slice(self), TreeIter::as_slice(other))
+    }
+
+    pub fn union<'a, C2, I2>(&'a self, other: &'a BST<C2, I2, Identity, O>) -> Union<'a, C::Output, O> where
+        C2: InsertableCollection<Output = C::Output>,
+        I2: Index
+    {
+        Union { inner: self.merge_iter(other) }
+    }
+
+    pub fn intersection<'a, C2, I2>(&'a self, other: &'a BST<C2, I2, Identity, O>) -> Intersection<'a, C::Output, O> where
+        C2: InsertableCollection<Output = C::Output>,
+        I2: Index
+    {
+        Intersection { inner: self.merge_iter(other) }
+    }
+
+    pub fn difference<'a, C2, I2>(&'a self, other: &'a BST<C2, I2, Identity, O>) -> Difference<'a, C::Output, O> where
+        C2: InsertableCollection<Output = C::Output>,
+        I2: Index
+    {
+        Difference { inner: self.merge_iter(other) }
+    }
+
+    pub fn symmetric_difference<'a, C2, I2>(&'a self, other: &'a BST<C2, I2, Identity, O>) -> SymmetricDifference<'a, C::Output, O> where
+        C2: InsertableCollection<Output = C::Output>,
+        I2: Index
+    {
+        SymmetricDifference { inner: self.merge_iter(other) }
+    }
+
+    pub fn is_subset<C2, I2>(&self, other: &BST<C2, I2, Identity, O>) -> bool where
+        C2: InsertableCollection<Output = C::Output>,
+        I2: Index
+    {
+        self.difference(other).next().is_none()
+    }
+
+    pub fn is_superset<C2, I2>(&self, other: &BST<C2, I2, Identity, O>) -> bool where
+        C2: InsertableCollection<Output = C::Output>,
+        I2: Index
+    {
+        other.is_subset(self)
+    }
+
+    pub fn is_disjoint<C2, I2>(&self, other: &BST<C2, I2, Identity, O>) -> bool where
+        C2: InsertableCollection<Output = C::Output>,
+        I2: Index
+    {
+        self.intersection(other).next().is_none()
+    }
+}
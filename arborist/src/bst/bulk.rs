@@ -0,0 +1,316 @@
+use arborist_core::fenwick::{
+    InsertableCollection, FenwickTreeError, Index
+};
+use arborist_core::{NodeKey, Compare, require};
+
+use super::{BST, BSTError, InsertError, DuplicatePolicy};
+
+use core::cell::Cell;
+use core::cmp::Ordering;
+
+// Bulk construction appends to the back of the collection, which never
+// shifts - sorting and merging then restore the in-order layout in bulk,
+// rather than paying an O(n) shift for every node
+
+// How bulk construction resolves equal keys. Trees that keep
+// duplicates (`Multi`) keep every equal key, whatever the choice
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Dedup {
+    Reject,
+    KeepFirst,
+    KeepLast
+}
+
+// Where the next node of a backwards merge comes from - `Both` drops the
+// left node in favour of its equal on the right
+enum Take {
+    Left,
+    Right,
+    Both
+}
+
+impl<C, I, N, O, P> BST<C, I, N, O, P> where
+    C: InsertableCollection,
+    C::Output: Sized,
+    I: Index,
+    N: NodeKey<C::Output>,
+    O: Compare<N::Key>,
+    P: DuplicatePolicy
+{
+    pub(super) fn compare_nodes(lhs: &C::Output, rhs: &C::Output) -> Result<Ordering, BSTError<I>> {
+        O::compare(N::key(lhs), N::key(rhs)).ok_or(BSTError::Incomparable)
+    }
+
+    pub(super) fn push(&mut self, node: C::Output) -> Result<(), BSTError<I>> {
+        let length: usize = self.inner.length();
+        // Walkers need every slot addressable, slot 0 included
+        require!(length < I::MAX.as_usize(), BSTError::Inner(FenwickTreeError::Overflow));
+        require!(self.inner.has_capacity(), BSTError::FULL);
+
+        self.inner.insert(length, node);
+        Ok(())
+    }
+
+    pub(super) fn truncate(&mut self, length: usize) {
+        while self.inner.length() > length {
+            self.inner.remove(self.inner.length() - 1);
+        }
+    }
+
+    // Stable, so equal keys keep the order they arrived in
+    fn sort_nodes(nodes: &mut [C::Output]) -> Result<(), BSTError<I>> {
+        let incomparable: Cell<bool> = Cell::new(false);
+        nodes.sort_by(| lhs, rhs | Self::compare_nodes(lhs, rhs).unwrap_or_else(| _ | {
+            incomparable.set(true);
+            Ordering::Equal
+        }));
+
+        require!(!incomparable.get(), BSTError::Incomparable);
+        Ok(())
+    }
+
+    // Compacts runs of equal keys to their front, returning how many nodes remain
+    fn dedup_nodes(nodes: &mut [C::Output], dedup: Dedup) -> Result<usize, BSTError<I>> {
+        if P::DUPLICATES || nodes.is_empty() {
+            return Ok(nodes.len());
+        }
+
+        let mut last: usize = 0;
+        for next in 1..nodes.len() {
+            match (Self::compare_nodes(&nodes[last], &nodes[next])?, dedup) {
+                (Ordering::Equal, Dedup::Reject) => return Err(BSTError::Unordered),
+                (Ordering::Equal, Dedup::KeepFirst) => {},
+                (Ordering::Equal, Dedup::KeepLast) => nodes.swap(last, next),
+                _ => {
+                    last += 1;
+                    nodes.swap(last, next);
+                }
+            }
+        }
+
+        Ok(last + 1)
+    }
+
+    fn merge_step(left: Option<&C::Output>, right: Option<&C::Output>) -> Result<Take, BSTError<I>> {
+        Ok(match (left, right) {
+            (Some(left), Some(right)) => match Self::compare_nodes(left, right)? {
+                Ordering::Greater => Take::Left,
+                // Equal right nodes arrived later, so sit further right
+                Ordering::Equal if !P::DUPLICATES => Take::Both,
+                _ => Take::Right
+            },
+            (Some(_), None) => Take::Left,
+            (None, _) => Take::Right
+        })
+    }
+
+    pub fn from_sorted_iter<T>(nodes: T, dedup: Dedup) -> Result<Self, BSTError<I>> where
+        T: IntoIterator<Item = C::Output>
+    {
+        let mut tree: Self = Self::new();
+
+        for node in nodes {
+            let last: usize = tree.inner.length() - 1;
+            if last != 0 {
+                match (Self::compare_nodes(&tree.inner[last], &node)?, dedup) {
+                    (Ordering::Less, _) => {},
+                    (Ordering::Equal, _) if P::DUPLICATES => {},
+                    (Ordering::Equal, Dedup::KeepFirst) => continue,
+                    (Ordering::Equal, Dedup::KeepLast) => {
+                        tree.inner[last] = node;
+                        continue;
+                    },
+                    _ => return Err(BSTError::Unordered)
+                }
+            }
+
+            tree.push(node)?;
+        }
+
+        Ok(tree)
+    }
+
+    pub fn from_unsorted<T>(nodes: T, dedup: Dedup) -> Result<Self, BSTError<I>> where
+        T: IntoIterator<Item = C::Output>
+    {
+        let mut tree: Self = Self::new();
+        for node in nodes {
+            tree.push(node)?;
+        }
+
+        let nodes: &mut [C::Output] = &mut tree.inner.as_mut_slice()[1..];
+        Self::sort_nodes(nodes)?;
+        let length: usize = Self::dedup_nodes(nodes, dedup)?;
+        tree.truncate(length + 1);
+
+        Ok(tree)
+    }
+
+    // Whether `node` repeats the key of the node merged just before it. Sources may
+    // keep duplicates where the tree doesn't - a tree without them keeps one of each
+    fn repeats(previous: Option<&C::Output>, node: &C::Output) -> Result<bool, BSTError<I>> {
+        Ok(match previous {
+            Some(previous) if !P::DUPLICATES => Self::compare_nodes(node, previous)? == Ordering::Equal,
+            _ => false
+        })
+    }
+
+    // Length of the merge of two sorted runs, found without moving anything
+    fn merged_length(left: &[C::Output], right: &[C::Output]) -> Result<usize, BSTError<I>> {
+        let (mut left, mut right, mut merged): (&[C::Output], &[C::Output], usize) = (left, right, 0);
+        let mut previous: Option<&C::Output> = None;
+        while !left.is_empty() || !right.is_empty() {
+            let node: Option<&C::Output> = match Self::merge_step(left.last(), right.last())? {
+                Take::Left => left.split_last().map(| (node, rest) | {
+                    left = rest;
+                    node
+                }),
+                Take::Right => right.split_last().map(| (node, rest) | {
+                    right = rest;
+                    node
+                }),
+                Take::Both => {
+                    left = &left[..left.len() - 1];
+                    right.split_last().map(| (node, rest) | {
+                        right = rest;
+                        node
+                    })
+                }
+            };
+
+            // `merge_step` only picks sources with nodes left
+            let node: &C::Output = node.ok_or(BSTError::EMPTY)?;
+            if !Self::repeats(previous, node)? {
+                merged += 1;
+            }
+            previous = Some(node);
+        }
+
+        Ok(merged)
+    }
+
+    // Merges from the back, taking the greatest remaining node each step.
+    // Both sources are left untouched if the merge can't fit
+    fn merge_back<L, R>(left: &mut L, right: &mut R) -> Result<Self, BSTError<I>> where
+        L: MergeSource<C::Output>,
+        R: MergeSource<C::Output>
+    {
+        let length: usize = Self::merged_length(left.nodes(), right.nodes())?;
+        let mut tree: Self = Self::new();
+        require!(length < I::MAX.as_usize(), BSTError::Inner(FenwickTreeError::Overflow));
+        require!(length <= tree.inner.remaining_capacity(), BSTError::FULL);
+
+        while !left.nodes().is_empty() || !right.nodes().is_empty() {
+            let node: Option<C::Output> = match Self::merge_step(left.nodes().last(), right.nodes().last())? {
+                Take::Left => left.take(),
+                Take::Right => right.take(),
+                Take::Both => {
+                    left.take();
+                    right.take()
+                }
+            };
+            let node: C::Output = node.ok_or(BSTError::EMPTY)?;
+
+            // Nodes arrive greatest first, so a repeated key keeps whichever sat last in order
+            if !Self::repeats(tree.inner.as_slice()[1..].last(), &node)? {
+                tree.push(node)?;
+            }
+        }
+
+        tree.inner.as_mut_slice()[1..].reverse();
+        Ok(tree)
+    }
+
+    // Later nodes replace equal ones already in the tree, as `insert` would.
+    // Incoming nodes are staged in a collection of the tree's own kind, so
+    // fixed-capacity trees never touch the heap. The tree is left untouched on error
+    pub fn try_extend<T>(&mut self, nodes: T) -> Result<(), BSTError<I>> where
+        T: IntoIterator<Item = C::Output>
+    {
+        let mut incoming: Self = Self::from_unsorted(nodes, Dedup::KeepLast)?;
+
+        *self = Self::merge_back(self, &mut incoming)?;
+        Ok(())
+    }
+
+    // Moves every node of `other` into the tree, leaving `other` empty.
+    // Nodes of `other` replace equal ones, and neither tree changes on error
+    pub fn append<C2, I2, P2>(&mut self, other: &mut BST<C2, I2, N, O, P2>) -> Result<(), BSTError<I>> where
+        C2: InsertableCollection<Output = C::Output>,
+        I2: Index
+    {
+        *self = Self::merge_back(self, other)?;
+        Ok(())
+    }
+
+    // Builds a tree out of two others in O(n + m), `right` winning on equal
+    // keys. Both are handed back, untouched, on error
+    #[allow(clippy::type_complexity)]
+    pub fn merge<CL, IL, PL, CR, IR, PR>(
+        mut left: BST<CL, IL, N, O, PL>,
+        mut right: BST<CR, IR, N, O, PR>
+    ) -> Result<Self, InsertError<(BST<CL, IL, N, O, PL>, BST<CR, IR, N, O, PR>), I>> where
+        CL: InsertableCollection<Output = C::Output>,
+        CR: InsertableCollection<Output = C::Output>,
+        IL: Index,
+        IR: Index
+    {
+        match Self::merge_back(&mut left, &mut right) {
+            Ok(tree) => Ok(tree),
+            Err(kind) => Err(InsertError::new(kind, (left, right)))
+        }
+    }
+}
+
+// Sorted nodes a merge takes from, greatest first
+trait MergeSource<T> {
+    fn nodes(&self) -> &[T];
+    fn take(&mut self) -> Option<T>;
+}
+
+impl<C, I, N, O, P> MergeSource<C::Output> for BST<C, I, N, O, P> where
+    C: InsertableCollection,
+    C::Output: Sized,
+    I: Index
+{
+    fn nodes(&self) -> &[C::Output] {
+        &self.inner.as_slice()[1..]
+    }
+
+    fn take(&mut self) -> Option<C::Output> {
+        match self.inner.length() {
+            1 => None,
+            length => Some(self.inner.remove(length - 1))
+        }
+    }
+}
+
+impl<C, I, N, O, P> FromIterator<C::Output> for BST<C, I, N, O, P> where
+    C: InsertableCollection,
+    C::Output: Sized,
+    I: Index,
+    N: NodeKey<C::Output>,
+    O: Compare<N::Key>,
+    P: DuplicatePolicy
+{
+    // Later nodes win over equal earlier ones. The trait can't fail,
+    // so this panics wherever `from_unsorted` would error
+    fn from_iter<T: IntoIterator<Item = C::Output>>(nodes: T) -> Self {
+        Self::from_unsorted(nodes, Dedup::KeepLast).expect("Failed to collect into BST")
+    }
+}
+
+impl<C, I, N, O, P> Extend<C::Output> for BST<C, I, N, O, P> where
+    C: InsertableCollection,
+    C::Output: Sized,
+    I: Index,
+    N: NodeKey<C::Output>,
+    O: Compare<N::Key>,
+    P: DuplicatePolicy
+{
+    // Later nodes replace equal ones, as `insert` would. The trait can't
+    // fail, so this panics wherever `try_extend` would error
+    fn extend<T: IntoIterator<Item = C::Output>>(&mut self, nodes: T) {
+        self.try_extend(nodes).expect("Failed to extend BST")
+    }
+}
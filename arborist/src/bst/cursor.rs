@@ -0,0 +1,277 @@
+use arborist_core::fenwick::{
+    InsertableCollection, IndexedCollection, IndexedCollectionMut,
+    FenwickTreeError, Length, Index
+};
+use arborist_core::{NodeKey, Identity, EntryKey, Compare, Natural, require};
+
+use super::{BST, BSTError, InsertError, DuplicatePolicy, Unique};
+
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+
+// Cursors sit on an element, or on the 'ghost' between the last and first
+// elements. Slot 0 of the backing collection is never used by the tree, so
+// it doubles as the ghost position. Storage order is in-order, so stepping
+// to a neighbour is a single slot in either direction.
+
+#[inline(always)]
+fn next_position(index: usize, length: usize) -> usize {
+    match index >= length {
+        true => 0,
+        false => index + 1
+    }
+}
+
+#[inline(always)]
+fn prev_position(index: usize, length: usize) -> usize {
+    match index {
+        0 => length,
+        _ => index - 1
+    }
+}
+
+// Equal neighbours are only in order when the tree keeps duplicates
+#[inline(always)]
+fn is_ordered<O: Compare<T>, P: DuplicatePolicy, T: ?Sized>(lesser: &T, greater: &T) -> bool {
+    match O::compare(lesser, greater) {
+        Some(Ordering::Less) => true,
+        Some(Ordering::Equal) => P::DUPLICATES,
+        _ => false
+    }
+}
+
+pub struct Cursor<'a, C: Length, I: Index = usize, N = Identity, O = Natural, P = Unique> {
+    tree: &'a BST<C, I, N, O, P>,
+    index: usize
+}
+
+pub struct CursorMut<'a, C: Length, I: Index = usize, N = Identity, O = Natural, P = Unique> {
+    tree: &'a mut BST<C, I, N, O, P>,
+    index: usize
+}
+
+/*################################
+           Navigation
+################################*/
+
+macro_rules! impl_cursor_nav {
+    ($cursor:ident $(, $lt:lifetime)?) => {
+        impl<'a, C, I, N, O, P> $cursor<'a, C, I, N, O, P> where
+            C: IndexedCollection,
+            C::Output: Sized,
+            I: Index
+        {
+            fn get(&self, index: usize) -> Option<&$($lt)? C::Output> {
+                match index {
+                    0 => None,
+                    _ => Some(&self.tree.inner[index])
+                }
+            }
+
+            // In-order position of the current element, None on the ghost
+            pub fn index(&self) -> Option<usize> {
+                self.index.checked_sub(1)
+            }
+
+            pub fn current(&self) -> Option<&$($lt)? C::Output> {
+                self.get(self.index)
+            }
+
+            pub fn peek_next(&self) -> Option<&$($lt)? C::Output> {
+                self.get(next_position(self.index, self.tree.length()))
+            }
+
+            pub fn peek_prev(&self) -> Option<&$($lt)? C::Output> {
+                self.get(prev_position(self.index, self.tree.length()))
+            }
+
+            pub fn move_next(&mut self) {
+                self.index = next_position(self.index, self.tree.length());
+            }
+
+            pub fn move_prev(&mut self) {
+                self.index = prev_position(self.index, self.tree.length());
+            }
+        }
+    };
+}
+
+impl_cursor_nav!{Cursor, 'a}
+impl_cursor_nav!{CursorMut}
+
+/*################################
+            Mutation
+################################*/
+
+// Only values are handed out mutably - keys must keep their relative order
+impl<'a, C, I, K, V, O, P> CursorMut<'a, C, I, EntryKey, O, P> where
+    C: IndexedCollectionMut<Output = (K, V)>,
+    I: Index,
+    K: 'a,
+    V: 'a
+{
+    pub fn current_value_mut(&mut self) -> Option<&mut V> {
+        match self.index {
+            0 => None,
+            index => Some(&mut self.tree.inner[index].1)
+        }
+    }
+}
+
+impl<'a, C, I, N, O, P> CursorMut<'a, C, I, N, O, P> where
+    C: InsertableCollection,
+    C::Output: Sized,
+    I: Index,
+    N: NodeKey<C::Output>,
+    O: Compare<N::Key>,
+    P: DuplicatePolicy
+{
+    pub fn as_cursor(&self) -> Cursor<'_, C, I, N, O, P> {
+        Cursor {
+            tree: self.tree,
+            index: self.index
+        }
+    }
+
+    // Inserting on the ghost appends to the back of the tree
+    pub fn insert_before(&mut self, node: C::Output) -> Result<(), BSTError<I>> {
+        Ok(self.try_insert_before(node)?)
+    }
+
+    // Inserting on the ghost prepends to the front of the tree
+    pub fn insert_after(&mut self, node: C::Output) -> Result<(), BSTError<I>> {
+        Ok(self.try_insert_after(node)?)
+    }
+
+    pub fn try_insert_before(&mut self, node: C::Output) -> Result<(), InsertError<C::Output, I>> {
+        let position: usize = match self.index {
+            0 => self.tree.length() + 1,
+            index => index
+        };
+
+        self.insert_at(position, node)?;
+        if self.index != 0 {
+            self.index += 1;
+        }
+
+        Ok(())
+    }
+
+    pub fn try_insert_after(&mut self, node: C::Output) -> Result<(), InsertError<C::Output, I>> {
+        self.insert_at(self.index + 1, node)
+    }
+
+    // Moves onto the following element, or the ghost if there is none
+    pub fn remove_current(&mut self) -> Option<C::Output> {
+        if self.index == 0 {
+            return None;
+        }
+
+        let removed: C::Output = self.tree.inner.remove(self.index);
+        if self.index > self.tree.length() {
+            self.index = 0;
+        }
+
+        Some(removed)
+    }
+
+    fn insert_at(&mut self, position: usize, node: C::Output) -> Result<(), InsertError<C::Output, I>> {
+        let length: usize = self.tree.length();
+        if let Some(prev) = position.checked_sub(1).and_then(| prev | self.get(prev)) {
+            require!(is_ordered::<O, P, _>(N::key(prev), N::key(&node)), InsertError::new(BSTError::Unordered, node));
+        }
+        if let Some(next) = Some(position).filter(| next | *next <= length).and_then(| next | self.get(next)) {
+            require!(is_ordered::<O, P, _>(N::key(&node), N::key(next)), InsertError::new(BSTError::Unordered, node));
+        }
+
+        // Slot 0 and the new node must both remain addressable
+        require!(length + 1 < I::MAX.as_usize(), InsertError::new(BSTError::Inner(FenwickTreeError::Overflow), node));
+        require!(self.tree.inner.has_capacity(), InsertError::new(BSTError::FULL, node));
+
+        self.tree.inner.insert(position, node);
+        Ok(())
+    }
+}
+
+/*################################
+          Constructors
+################################*/
+
+impl<C, I, N, O, P> BST<C, I, N, O, P> where
+    C: IndexedCollection,
+    C::Output: Sized,
+    I: Index
+{
+    fn lower_bound_index<Q>(&self, key: &Q) -> Result<usize, BSTError<I>> where
+        N: NodeKey<C::Output>,
+        N::Key: Borrow<Q>,
+        O: Compare<Q>,
+        Q: ?Sized
+    {
+        let index: usize = self.bound_position(key, false)?;
+
+        Ok(match index > self.length() {
+            true => 0,
+            false => index
+        })
+    }
+
+    // Positions the cursor on the first element not less than `key`
+    pub fn lower_bound_cursor<Q>(&self, key: &Q) -> Result<Cursor<'_, C, I, N, O, P>, BSTError<I>> where
+        N: NodeKey<C::Output>,
+        N::Key: Borrow<Q>,
+        O: Compare<Q>,
+        Q: ?Sized
+    {
+        Ok(Cursor {
+            index: self.lower_bound_index(key)?,
+            tree: self
+        })
+    }
+
+    pub fn cursor_front(&self) -> Cursor<'_, C, I, N, O, P> {
+        Cursor {
+            index: next_position(0, self.length()),
+            tree: self
+        }
+    }
+
+    pub fn cursor_back(&self) -> Cursor<'_, C, I, N, O, P> {
+        Cursor {
+            index: prev_position(0, self.length()),
+            tree: self
+        }
+    }
+}
+
+impl<C, I, N, O, P> BST<C, I, N, O, P> where
+    C: IndexedCollectionMut,
+    C::Output: Sized,
+    I: Index
+{
+    pub fn lower_bound_cursor_mut<Q>(&mut self, key: &Q) -> Result<CursorMut<'_, C, I, N, O, P>, BSTError<I>> where
+        N: NodeKey<C::Output>,
+        N::Key: Borrow<Q>,
+        O: Compare<Q>,
+        Q: ?Sized
+    {
+        Ok(CursorMut {
+            index: self.lower_bound_index(key)?,
+            tree: self
+        })
+    }
+
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, C, I, N, O, P> {
+        CursorMut {
+            index: next_position(0, self.length()),
+            tree: self
+        }
+    }
+
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, C, I, N, O, P> {
+        CursorMut {
+            index: prev_position(0, self.length()),
+            tree: self
+        }
+    }
+}
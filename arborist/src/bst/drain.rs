@@ -0,0 +1,162 @@
+use arborist_core::fenwick::{InsertableCollection, Index};
+use arborist_core::{TreeRange, NodeKey, Identity, Compare, Natural};
+
+use super::{BST, BSTError, DuplicatePolicy, Unique};
+
+use core::borrow::Borrow;
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::ops::{Range, RangeBounds};
+
+// Drained nodes leave the tree before the iterator is handed out, held
+// back to front so each is an O(1) pop - the tree is consistent however
+// the iterator ends up, leaked or not, and slot 0 is never touched
+
+pub struct Drain<'a, C, I = usize, N = Identity, O = Natural, P = Unique> where
+    C: InsertableCollection,
+    C::Output: Sized,
+    I: Index
+{
+    nodes: C,
+    tree: PhantomData<&'a mut BST<C, I, N, O, P>>
+}
+
+impl<'a, C, I, N, O, P> Drain<'a, C, I, N, O, P> where
+    C: InsertableCollection,
+    C::Output: Sized,
+    I: Index
+{
+    // Takes `nodes` in order
+    fn new(mut nodes: C) -> Self {
+        nodes.as_mut_slice().reverse();
+
+        Self {
+            nodes,
+            tree: PhantomData
+        }
+    }
+}
+
+impl<'a, C, I, N, O, P> Iterator for Drain<'a, C, I, N, O, P> where
+    C: InsertableCollection,
+    C::Output: Sized,
+    I: Index
+{
+    type Item = C::Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.nodes.length() {
+            0 => None,
+            length => Some(self.nodes.remove(length - 1))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.nodes.length(), Some(self.nodes.length()))
+    }
+}
+
+impl<'a, C, I, N, O, P> ExactSizeIterator for Drain<'a, C, I, N, O, P> where
+    C: InsertableCollection,
+    C::Output: Sized,
+    I: Index
+{}
+
+impl<'a, C, I, N, O, P> FusedIterator for Drain<'a, C, I, N, O, P> where
+    C: InsertableCollection,
+    C::Output: Sized,
+    I: Index
+{}
+
+// Finishes a `retain` however it ends - should `keep` panic, the nodes
+// it never reached stay, closing the gap left by those it dropped
+struct Retain<'a, C> where
+    C: InsertableCollection,
+    C::Output: Sized
+{
+    nodes: &'a mut C,
+    read: usize,
+    write: usize
+}
+
+impl<'a, C> Drop for Retain<'a, C> where
+    C: InsertableCollection,
+    C::Output: Sized
+{
+    fn drop(&mut self) {
+        let dropped: usize = self.read - self.write;
+        self.nodes.as_mut_slice()[self.write..].rotate_left(dropped);
+
+        let length: usize = self.nodes.length() - dropped;
+        while self.nodes.length() > length {
+            self.nodes.remove(self.nodes.length() - 1);
+        }
+    }
+}
+
+impl<C, I, N, O, P> BST<C, I, N, O, P> where
+    C: InsertableCollection,
+    C::Output: Sized,
+    I: Index,
+    N: NodeKey<C::Output>,
+    O: Compare<N::Key>,
+    P: DuplicatePolicy
+{
+    // Kept nodes swap down to the write cursor as the read cursor passes
+    // them, so they keep their order and the rest gather at the back
+    pub fn retain<F>(&mut self, mut keep: F) where
+        F: FnMut(&C::Output) -> bool
+    {
+        let mut guard: Retain<C> = Retain { nodes: &mut self.inner, read: 1, write: 1 };
+
+        while guard.read < guard.nodes.length() {
+            let read: usize = guard.read;
+            let kept: bool = keep(&guard.nodes[read]);
+            guard.read += 1;
+
+            if kept {
+                guard.nodes.as_mut_slice().swap(guard.write, read);
+                guard.write += 1;
+            }
+        }
+    }
+
+    pub fn drain(&mut self) -> Drain<'_, C, I, N, O, P> {
+        Drain::new(self.inner.split_off(1))
+    }
+
+    pub fn drain_range<Q, R>(&mut self, range: R) -> Result<Drain<'_, C, I, N, O, P>, BSTError<I>> where
+        N::Key: Borrow<Q>,
+        O: Compare<Q>,
+        Q: ?Sized,
+        R: RangeBounds<Q>
+    {
+        let span: Range<usize> = TreeRange::range_span(self, range)?;
+        // Rotate the range behind everything after it
+        self.inner.as_mut_slice()[span.start + 1..].rotate_left(span.len());
+
+        let position: usize = self.inner.length() - span.len();
+        Ok(Drain::new(self.inner.split_off(position)))
+    }
+
+    // `extract` runs over every node up front, so dropping the
+    // iterator early still removes everything it picked
+    pub fn extract_if<F>(&mut self, mut extract: F) -> Drain<'_, C, I, N, O, P> where
+        F: FnMut(&C::Output) -> bool
+    {
+        let mut rest: C = self.inner.split_off(1);
+        let mut picked: C = C::new();
+        rest.as_mut_slice().reverse();
+
+        while rest.length() != 0 {
+            let node: C::Output = rest.remove(rest.length() - 1);
+            let target: &mut C = match extract(&node) {
+                true => &mut picked,
+                false => &mut self.inner
+            };
+            target.insert(target.length(), node);
+        }
+
+        Drain::new(picked)
+    }
+}
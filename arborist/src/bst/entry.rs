@@ -0,0 +1,148 @@
+use arborist_core::fenwick::{InsertableCollection, FenwickTreeError, Index};
+use arborist_core::{EntryKey, Compare, Natural, require};
+
+use super::{BST, BSTError, BSTWalkerResult, InsertError};
+
+// Entries hold on to the slot found by a single `BSTWalker::allocate`, so
+// inserting into a vacant entry doesn't walk the tree a second time
+
+pub enum Entry<'a, C: InsertableCollection, K, I: Index = usize, O = Natural> {
+    Occupied(OccupiedEntry<'a, C, I, O>),
+    Vacant(VacantEntry<'a, C, K, I, O>)
+}
+
+pub struct OccupiedEntry<'a, C: InsertableCollection, I: Index = usize, O = Natural> {
+    tree: &'a mut BST<C, I, EntryKey, O>,
+    index: usize
+}
+
+pub struct VacantEntry<'a, C: InsertableCollection, K, I: Index = usize, O = Natural> {
+    tree: &'a mut BST<C, I, EntryKey, O>,
+    key: K,
+    index: usize
+}
+
+impl<C, K, V, I, O> BST<C, I, EntryKey, O> where
+    C: InsertableCollection<Output = (K, V)>,
+    I: Index,
+    O: Compare<K>
+{
+    pub fn entry(&mut self, key: K) -> Result<Entry<'_, C, K, I, O>, BSTError<I>> {
+        Ok(match self.allocate(&key)? {
+            BSTWalkerResult::Existing(index) => Entry::Occupied(OccupiedEntry {
+                tree: self,
+                index: index.as_usize()
+            }),
+            BSTWalkerResult::New(index, side) => Entry::Vacant(VacantEntry {
+                tree: self,
+                key,
+                index: index.as_usize() + side as usize
+            })
+        })
+    }
+}
+
+impl<'a, C, K, V, I, O> Entry<'a, C, K, I, O> where
+    C: InsertableCollection<Output = (K, V)>,
+    K: 'a,
+    V: 'a,
+    I: Index
+{
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key()
+        }
+    }
+
+    pub fn or_insert(self, default: V) -> Result<&'a mut V, BSTError<I>> {
+        self.or_insert_with(|| default)
+    }
+
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> Result<&'a mut V, BSTError<I>> {
+        match self {
+            Entry::Occupied(entry) => Ok(entry.into_mut()),
+            Entry::Vacant(entry) => entry.insert(default())
+        }
+    }
+
+    pub fn or_default(self) -> Result<&'a mut V, BSTError<I>> where
+        V: Default
+    {
+        self.or_insert_with(V::default)
+    }
+
+    pub fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+        if let Entry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+
+        self
+    }
+}
+
+impl<'a, C, K, V, I, O> OccupiedEntry<'a, C, I, O> where
+    C: InsertableCollection<Output = (K, V)>,
+    K: 'a,
+    V: 'a,
+    I: Index
+{
+    pub fn key(&self) -> &K {
+        &self.tree.inner[self.index].0
+    }
+
+    pub fn get(&self) -> &V {
+        &self.tree.inner[self.index].1
+    }
+
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.tree.inner[self.index].1
+    }
+
+    pub fn into_mut(self) -> &'a mut V {
+        &mut self.tree.inner[self.index].1
+    }
+
+    pub fn insert(&mut self, value: V) -> V {
+        core::mem::replace(self.get_mut(), value)
+    }
+
+    pub fn remove(self) -> V {
+        self.remove_entry().1
+    }
+
+    pub fn remove_entry(self) -> (K, V) {
+        self.tree.inner.remove(self.index)
+    }
+}
+
+impl<'a, C, K, V, I, O> VacantEntry<'a, C, K, I, O> where
+    C: InsertableCollection<Output = (K, V)>,
+    K: 'a,
+    V: 'a,
+    I: Index
+{
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    pub fn into_key(self) -> K {
+        self.key
+    }
+
+    pub fn insert(self, value: V) -> Result<&'a mut V, BSTError<I>> {
+        Ok(self.try_insert(value)?)
+    }
+
+    // Hands back both key and value when the tree can't grow
+    pub fn try_insert(self, value: V) -> Result<&'a mut V, InsertError<(K, V), I>> {
+        require!(
+            self.tree.inner.length() < I::MAX.as_usize(),
+            InsertError::new(BSTError::Inner(FenwickTreeError::Overflow), (self.key, value))
+        );
+        require!(self.tree.inner.has_capacity(), InsertError::new(BSTError::FULL, (self.key, value)));
+
+        self.tree.inner.insert(self.index, (self.key, value));
+        Ok(&mut self.tree.inner[self.index].1)
+    }
+}
@@ -0,0 +1,148 @@
+use arborist_core::fenwick::{
+    InsertableCollection, Length, Index
+};
+use arborist_core::{
+    TreeIter, TreeRange, NodeKey, EntryKey, Compare
+};
+use arborist_core::tree_kv::{TreeIterKVMut, IterMut};
+
+use super::{BST, BSTError, DuplicatePolicy};
+
+use core::borrow::Borrow;
+use core::iter::FusedIterator;
+use core::ops::{Bound, Range, RangeBounds};
+use core::slice;
+
+// Storage order is in-order, so iterating a tree is iterating its
+// backing slice - minus slot 0, which the tree never uses
+
+impl<C, I, N, O, P> TreeIter for BST<C, I, N, O, P> where
+    C: InsertableCollection,
+    C::Output: Sized,
+    I: Index,
+    N: NodeKey<C::Output>,
+    O: Compare<N::Key>,
+    P: DuplicatePolicy
+{
+    fn as_slice(&self) -> &[C::Output] {
+        self.inner.as_slice().get(1..).unwrap_or(&[])
+    }
+}
+
+// Maps lend out their values mutably, never their keys - sets lend out nothing
+impl<C, K, V, I, O, P> TreeIterKVMut<K, V, BSTError<I>> for BST<C, I, EntryKey, O, P> where
+    C: InsertableCollection<Output = (K, V)>,
+    I: Index,
+    O: Compare<K>,
+    P: DuplicatePolicy
+{
+    fn iter_mut<'a>(&'a mut self) -> IterMut<'a, K, V> where K: 'a, V: 'a {
+        IterMut::from(self.inner.as_mut_slice().get_mut(1..).unwrap_or(&mut []))
+    }
+}
+
+impl<C, I, N, O, P> TreeRange for BST<C, I, N, O, P> where
+    C: InsertableCollection,
+    C::Output: Sized,
+    I: Index,
+    N: NodeKey<C::Output>,
+    O: Compare<N::Key>,
+    P: DuplicatePolicy
+{
+    fn range_span<Q, R>(&self, range: R) -> Result<Range<usize>, BSTError<I>> where
+        N::Key: Borrow<Q>,
+        O: Compare<Q>,
+        Q: ?Sized,
+        R: RangeBounds<Q>
+    {
+        let start: usize = match range.start_bound() {
+            Bound::Included(key) => self.bound_position(key, false)?,
+            Bound::Excluded(key) => self.bound_position(key, true)?,
+            Bound::Unbounded => 1
+        };
+        let end: usize = match range.end_bound() {
+            Bound::Included(key) => self.bound_position(key, true)?,
+            Bound::Excluded(key) => self.bound_position(key, false)?,
+            Bound::Unbounded => self.length() + 1
+        };
+
+        // Inverted ranges are empty rather than an error
+        Ok(start.saturating_sub(1)..end.max(start).saturating_sub(1))
+    }
+}
+
+pub struct IntoIter<T> {
+    inner: T
+}
+
+impl<T: Iterator> Iterator for IntoIter<T> {
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T: DoubleEndedIterator> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<T: ExactSizeIterator> ExactSizeIterator for IntoIter<T> {}
+impl<T: FusedIterator> FusedIterator for IntoIter<T> {}
+
+impl<C, I, N, O, P> IntoIterator for BST<C, I, N, O, P> where
+    C: InsertableCollection + IntoIterator<Item = <C as core::ops::Index<usize>>::Output>,
+    <C as core::ops::Index<usize>>::Output: Sized,
+    I: Index
+{
+    type Item = C::Item;
+    type IntoIter = IntoIter<C::IntoIter>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        // Slot 0 may never have been initialised - split the nodes off
+        // it, then forget it rather than move it out or drop it
+        let mut sentinel: C = self.inner;
+        let inner: C = sentinel.split_off(1);
+        sentinel.set_length(0);
+
+        IntoIter { inner: inner.into_iter() }
+    }
+}
+
+impl<'a, C, I, N, O, P> IntoIterator for &'a BST<C, I, N, O, P> where
+    C: InsertableCollection,
+    C::Output: Sized,
+    I: Index,
+    N: NodeKey<C::Output>,
+    O: Compare<N::Key>,
+    P: DuplicatePolicy
+{
+    type Item = &'a C::Output;
+    type IntoIter = slice::Iter<'a, C::Output>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        TreeIter::iter(self)
+    }
+}
+
+impl<'a, C, K, V, I, O, P> IntoIterator for &'a mut BST<C, I, EntryKey, O, P> where
+    C: InsertableCollection<Output = (K, V)>,
+    K: 'a,
+    V: 'a,
+    I: Index,
+    O: Compare<K>,
+    P: DuplicatePolicy
+{
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        TreeIterKVMut::iter_mut(self)
+    }
+}
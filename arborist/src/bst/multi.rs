@@ -0,0 +1,74 @@
+use arborist_core::fenwick::{InsertableCollection, Index};
+use arborist_core::{NodeKey, Compare, require};
+
+use super::{BST, BSTError, Multi};
+
+use core::borrow::Borrow;
+use core::ops::Range;
+
+// Equal keys sit in one contiguous run, in insertion order, so
+// every lookup below works off the two ends of that run
+
+impl<C, I, N, O> BST<C, I, N, O, Multi> where
+    C: InsertableCollection,
+    C::Output: Sized,
+    I: Index,
+    N: NodeKey<C::Output>,
+    O: Compare<N::Key>
+{
+    fn run<Q>(&self, key: &Q) -> Result<Range<usize>, BSTError<I>> where
+        N::Key: Borrow<Q>,
+        O: Compare<Q>,
+        Q: ?Sized
+    {
+        Ok(self.bound_position(key, false)?..self.bound_position(key, true)?)
+    }
+
+    pub fn count<Q>(&self, key: &Q) -> Result<usize, BSTError<I>> where
+        N::Key: Borrow<Q>,
+        O: Compare<Q>,
+        Q: ?Sized
+    {
+        Ok(self.run(key)?.len())
+    }
+
+    pub fn get_all<Q>(&self, key: &Q) -> Result<&[C::Output], BSTError<I>> where
+        N::Key: Borrow<Q>,
+        O: Compare<Q>,
+        Q: ?Sized
+    {
+        let run: Range<usize> = self.run(key)?;
+
+        Ok(&self.inner.as_slice()[run])
+    }
+
+    // Removes the earliest inserted of the equal keys
+    pub fn remove_one<Q>(&mut self, key: &Q) -> Result<C::Output, BSTError<I>> where
+        N::Key: Borrow<Q>,
+        O: Compare<Q>,
+        Q: ?Sized
+    {
+        let run: Range<usize> = self.run(key)?;
+        require!(!run.is_empty(), BSTError::KeyNotFound);
+
+        Ok(self.inner.remove(run.start))
+    }
+
+    // Returns how many nodes were removed
+    pub fn remove_all<Q>(&mut self, key: &Q) -> Result<usize, BSTError<I>> where
+        N::Key: Borrow<Q>,
+        O: Compare<Q>,
+        Q: ?Sized
+    {
+        let run: Range<usize> = self.run(key)?;
+        let length: usize = self.inner.length();
+
+        // Shift the run to the back, where each removal is O(1)
+        self.inner.as_mut_slice()[run.start..].rotate_left(run.len());
+        for index in (length - run.len()..length).rev() {
+            self.inner.remove(index);
+        }
+
+        Ok(run.len())
+    }
+}
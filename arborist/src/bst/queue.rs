@@ -0,0 +1,167 @@
+use arborist_core::fenwick::{InsertableCollection, Length, Index};
+use arborist_core::{TreeRead, TreeWrite, NodeKey, Compare, require};
+
+use super::{BST, BSTError, InsertError, DuplicatePolicy};
+
+use core::cmp::Ordering;
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+// Storage order is in-order, so both ends of the tree are the two ends
+// of its backing slice - the least node sits in slot 1, the greatest last
+
+impl<C, I, N, O, P> BST<C, I, N, O, P> where
+    C: InsertableCollection,
+    C::Output: Sized,
+    I: Index,
+    N: NodeKey<C::Output>,
+    O: Compare<N::Key>,
+    P: DuplicatePolicy
+{
+    pub fn peek_first(&self) -> Result<&C::Output, BSTError<I>> {
+        TreeRead::first(self)
+    }
+
+    pub fn peek_last(&self) -> Result<&C::Output, BSTError<I>> {
+        TreeRead::last(self)
+    }
+
+    // Every node after the first shifts down a slot, so this is O(n) - `pop_last` is O(1)
+    pub fn pop_first(&mut self) -> Result<C::Output, BSTError<I>> {
+        require!(self.length() != 0, BSTError::EMPTY);
+
+        Ok(self.inner.remove(1))
+    }
+
+    pub fn pop_last(&mut self) -> Result<C::Output, BSTError<I>> {
+        TreeWrite::pop(self)
+    }
+}
+
+// Which end of a `BoundedBST` survives once it is full
+pub trait BoundPolicy {
+    const LARGEST: bool;
+}
+
+// Keeps the greatest nodes, evicting the least
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Largest;
+
+// Keeps the least nodes, evicting the greatest
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Smallest;
+
+impl BoundPolicy for Largest {
+    const LARGEST: bool = true;
+}
+
+impl BoundPolicy for Smallest {
+    const LARGEST: bool = false;
+}
+
+// A tree holding at most `bound` nodes - the top k by its order, on whichever end `B` picks
+pub struct BoundedBST<T, B = Largest> {
+    tree: T,
+    bound: usize,
+    policy: PhantomData<B>
+}
+
+impl<C, I, N, O, P, B> BoundedBST<BST<C, I, N, O, P>, B> where
+    C: InsertableCollection,
+    C::Output: Sized,
+    I: Index,
+    N: NodeKey<C::Output>,
+    O: Compare<N::Key>,
+    P: DuplicatePolicy,
+    B: BoundPolicy
+{
+    // Bounds past the capacity of the backing collection are clamped to it
+    pub fn new(bound: usize) -> Self {
+        let tree: BST<C, I, N, O, P> = BST::new();
+
+        Self {
+            bound: bound.min(tree.inner.remaining_capacity()),
+            tree,
+            policy: PhantomData
+        }
+    }
+
+    pub fn bound(&self) -> usize {
+        self.bound
+    }
+
+    pub fn into_inner(self) -> BST<C, I, N, O, P> {
+        self.tree
+    }
+
+    // Ties with the node next up for eviction keep the node already held.
+    // Returns whichever node no longer fits: the one `node` replaced, the one
+    // it evicted, or `node` itself when it ranks below everything held
+    pub fn insert(&mut self, node: C::Output) -> Result<Option<C::Output>, BSTError<I>> {
+        Ok(self.try_insert(node)?)
+    }
+
+    pub fn try_insert(&mut self, node: C::Output) -> Result<Option<C::Output>, InsertError<C::Output, I>> {
+        if self.tree.length() < self.bound {
+            return self.tree.try_insert(node);
+        }
+        if self.bound == 0 {
+            return Ok(Some(node));
+        }
+
+        let edge: usize = self.edge();
+        let ordering: Ordering = match BST::<C, I, N, O, P>::compare_nodes(&node, &self.tree.inner[edge]) {
+            Ok(ordering) => ordering,
+            Err(kind) => return Err(InsertError::new(kind, node))
+        };
+        match (ordering, B::LARGEST) {
+            (Ordering::Less, true) | (Ordering::Greater, false) => return Ok(Some(node)),
+            (Ordering::Equal, _) if P::DUPLICATES => return Ok(Some(node)),
+            _ => {}
+        }
+
+        // Equal keys replace rather than evict
+        if !P::DUPLICATES {
+            match self.tree.contains(N::key(&node)) {
+                Ok(true) => return self.tree.try_insert(node),
+                Ok(false) => {},
+                Err(kind) => return Err(InsertError::new(kind, node))
+            }
+        }
+
+        // Evicting first frees the slot `node` needs - should it still
+        // fail to go in, the evicted node goes straight back where it was
+        let evicted: C::Output = self.tree.inner.remove(edge);
+        if let Err(err) = self.tree.try_insert(node) {
+            self.tree.inner.insert(edge, evicted);
+            return Err(err);
+        }
+
+        Ok(Some(evicted))
+    }
+
+    // Slot of the node next up for eviction, on a non-empty tree
+    fn edge(&self) -> usize {
+        match B::LARGEST {
+            true => 1,
+            false => self.tree.length()
+        }
+    }
+
+    pub fn pop_first(&mut self) -> Result<C::Output, BSTError<I>> {
+        self.tree.pop_first()
+    }
+
+    pub fn pop_last(&mut self) -> Result<C::Output, BSTError<I>> {
+        self.tree.pop_last()
+    }
+}
+
+// Reads go straight to the tree - writes must go through the bound
+impl<T, B> Deref for BoundedBST<T, B> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.tree
+    }
+}
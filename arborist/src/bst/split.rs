@@ -0,0 +1,85 @@
+use arborist_core::fenwick::{
+    InsertableCollection, FenwickTreeError, Length, Index
+};
+use arborist_core::{NodeKey, Compare, require};
+
+use super::{BST, BSTError, InsertError, DuplicatePolicy};
+
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+
+impl<C, I, N, O, P> BST<C, I, N, O, P> where
+    C: InsertableCollection,
+    C::Output: Sized,
+    I: Index,
+    N: NodeKey<C::Output>,
+    O: Compare<N::Key>,
+    P: DuplicatePolicy
+{
+    // Hands back the nodes from slot `position` onwards as their own tree
+    fn split_at_position(&mut self, position: usize) -> Self {
+        if position <= 1 {
+            return core::mem::replace(self, Self::new());
+        }
+
+        // The split off nodes need a slot 0 ahead of them - they're moved
+        // behind a fresh one from the back, then put back in order
+        let mut tail: C = self.inner.split_off(position);
+        let mut right: Self = Self::new();
+        while tail.length() != 0 {
+            right.inner.insert(right.inner.length(), tail.remove(tail.length() - 1));
+        }
+        right.inner.as_mut_slice()[1..].reverse();
+
+        right
+    }
+
+    // Keeps the nodes ordered before `key`, returning the rest
+    pub fn split_off<Q>(&mut self, key: &Q) -> Result<Self, BSTError<I>> where
+        N::Key: Borrow<Q>,
+        O: Compare<Q>,
+        Q: ?Sized
+    {
+        let position: usize = self.bound_position(key, false)?;
+
+        Ok(self.split_at_position(position))
+    }
+
+    // Keeps the first `n` nodes, returning the rest
+    pub fn split_at_rank(&mut self, n: usize) -> Self {
+        self.split_at_position(n.min(self.length()) + 1)
+    }
+
+    // Joins two trees whose key ranges don't overlap, every key of `left`
+    // being ordered before every key of `right` - both are handed back on error
+    pub fn concat(mut left: Self, mut right: Self) -> Result<Self, InsertError<(Self, Self), I>> {
+        let left_length: usize = left.length();
+        if let Err(kind) = Self::check_concat(&left, &right) {
+            return Err(InsertError::new(kind, (left, right)));
+        }
+
+        // Taking from the back of `right` is O(1) a node, then one pass restores its order
+        while right.length() != 0 {
+            left.inner.insert(left.inner.length(), right.inner.remove(right.length()));
+        }
+        left.inner.as_mut_slice()[left_length + 1..].reverse();
+
+        Ok(left)
+    }
+
+    fn check_concat(left: &Self, right: &Self) -> Result<(), BSTError<I>> {
+        let (left_length, right_length): (usize, usize) = (left.length(), right.length());
+        if left_length != 0 && right_length != 0 {
+            let ordering: Ordering = Self::compare_nodes(&left.inner[left_length], &right.inner[1])?;
+            require!(
+                ordering == Ordering::Less || (P::DUPLICATES && ordering == Ordering::Equal),
+                BSTError::Unordered
+            );
+        }
+
+        require!(left_length + right_length < I::MAX.as_usize(), BSTError::Inner(FenwickTreeError::Overflow));
+        require!(right_length <= left.inner.remaining_capacity(), BSTError::FULL);
+
+        Ok(())
+    }
+}
@@ -8,43 +8,45 @@ use arborist_core::fenwick::{
 use arborist_core::{
     TreeRead, TreeReadMut, TreeWrite,
     TreeWalker, Direction, NodeSide,
-    Height, require, unwrap_enum
+    Height, Compare, Natural,
+    require, unwrap_enum
 };
-use arborist_core::tree_kv::NodeKV;
 
 use crate::bst::{BST, BSTWalker, BSTError, BSTWalkerResult};
 
 use core::ops::{
     Deref, DerefMut
 };
+use core::borrow::Borrow;
+use core::marker::PhantomData;
 
 const MAX_ELEMENTS: usize = 16;
 
 #[cfg(feature = "bumpalo_vec")]
 pub mod bumpalo_vec {
     use bumpalo::collections::Vec;
-    use super::{BTree, NodeKV};
+    use super::{BTree, Natural};
 
-    pub type BTreeSet<T> = BTree<Vec<T>>;
-    pub type BTreeMap<'t, K, V> = BTree<Vec<NodeKV<'t, K, V>>>;
+    pub type BTreeSet<T, O = Natural> = BTree<Vec<T>, O>;
+    pub type BTreeMap<K, V, O = Natural> = BTree<Vec<(K, V)>, O>;
 }
 
 #[cfg(feature = "std_vec")]
 pub mod std_vec {
     use std::vec::Vec;
-    use super::{BTree, NodeKV};
+    use super::{BTree, Natural};
 
-    pub type BTreeSet<T> = BTree<Vec<T>>;
-    pub type BTreeMap<'t, K, V> = BTree<Vec<NodeKV<'t, K, V>>>;
+    pub type BTreeSet<T, O = Natural> = BTree<Vec<T>, O>;
+    pub type BTreeMap<K, V, O = Natural> = BTree<Vec<(K, V)>, O>;
 }
 
 #[cfg(feature = "const_vec")]
 pub mod const_vec {
     use tinyvec::ArrayVec;
-    use super::{BTree, NodeKV, MAX_ELEMENTS};
+    use super::{BTree, Natural, MAX_ELEMENTS};
 
-    pub type BTreeSetConst<T> = BTree<ArrayVec<[T; MAX_ELEMENTS]>>;
-    pub type BTreeMapConst<'t, K, V> = BTree<ArrayVec<[NodeKV<'t, K, V>; MAX_ELEMENTS]>>;
+    pub type BTreeSetConst<T, O = Natural> = BTree<ArrayVec<[T; MAX_ELEMENTS]>, O>;
+    pub type BTreeMapConst<K, V, O = Natural> = BTree<ArrayVec<[(K, V); MAX_ELEMENTS]>, O>;
 }
 
 pub mod btreemap {
@@ -82,12 +84,13 @@ pub enum BTreeNode<'t, T> {
 
 #[derive(Length)]
 #[length_method(self.length)]
-pub struct BTree<C: InsertableCollection> {
+pub struct BTree<C: InsertableCollection, O = Natural> {
     pub(crate) root_tree: BST<C>,
-    length: usize
+    length: usize,
+    order: PhantomData<O>
 }
 
-impl<'t, C, I> BTree<C> where
+impl<'t, C, I, O> BTree<C, O> where
     C: InsertableCollection<Output = BTreeNode<'t, BST<I>>>,
     C::Output: PartialOrd + Sized,
     I: InsertableCollection + 't,
@@ -98,25 +101,35 @@ impl<'t, C, I> BTree<C> where
         // BST Collections *always* allocate at least 1 slot
         Self {
             root_tree: BST::from(C::new()),
-            length: 0
+            length: 0,
+            order: PhantomData
         }
     }
 }
 
-impl<'t, C, I> TreeRead for BTree<C> where
+impl<'t, C, I, O> TreeRead for BTree<C, O> where
     C: InsertableCollection<Output = BTreeNode<'t, BST<I>>>,
-    C::Output: PartialOrd + Sized,
+    BTreeNode<'t, BST<I>>: PartialOrd,
     I: InsertableCollection + 't,
-    I::Output: PartialOrd<C::Output> + Sized
+    I::Output: Sized,
+    O: Compare<I::Output> + Compare<I::Output, BTreeNode<'t, BST<I>>>
 {
     type Node = I::Output;
+    type Key = I::Output;
+    type Order = O;
     type Error = BTreeError;
 
-    fn get(&self, node: &Self::Node) -> Result<&Self::Node, Self::Error> {
+    fn get<Q: ?Sized>(&self, key: &Q) -> Result<&Self::Node, Self::Error> where
+        Self::Key: Borrow<Q>,
+        Self::Order: Compare<Q>
+    {
         todo!()
     }
 
-    fn contains(&self, node: &Self::Node) -> Result<bool, Self::Error> {
+    fn contains<Q: ?Sized>(&self, key: &Q) -> Result<bool, Self::Error> where
+        Self::Key: Borrow<Q>,
+        Self::Order: Compare<Q>
+    {
         todo!()
     }
 
@@ -133,13 +146,17 @@ impl<'t, C, I> TreeRead for BTree<C> where
     }
 }
 
-impl<'t, C, I> TreeReadMut for BTree<C> where
+impl<'t, C, I, O> TreeReadMut for BTree<C, O> where
     C: InsertableCollection<Output = BTreeNode<'t, BST<I>>>,
-    C::Output: PartialOrd + Sized,
+    BTreeNode<'t, BST<I>>: PartialOrd,
     I: InsertableCollection + 't,
-    I::Output: PartialOrd<C::Output> + Sized
+    I::Output: Sized,
+    O: Compare<I::Output> + Compare<I::Output, BTreeNode<'t, BST<I>>>
 {
-    fn get_mut(&mut self, node: &Self::Node) -> Result<&mut Self::Node, Self::Error> {
+    fn get_mut<Q: ?Sized>(&mut self, key: &Q) -> Result<&mut Self::Node, Self::Error> where
+        Self::Key: Borrow<Q>,
+        Self::Order: Compare<Q>
+    {
         todo!()
     }
 
@@ -156,14 +173,16 @@ impl<'t, C, I> TreeReadMut for BTree<C> where
     }
 }
 
-impl<'t, C, I> TreeWrite for BTree<C> where
+impl<'t, C, I, O> TreeWrite for BTree<C, O> where
     C: InsertableCollection<Output = BTreeNode<'t, BST<I>>>,
-    C::Output: PartialOrd + Sized,
+    BTreeNode<'t, BST<I>>: PartialOrd,
     I: InsertableCollection + 't,
-    I::Output: PartialOrd<C::Output> + Sized
+    I::Output: Sized,
+    O: Compare<I::Output> + Compare<I::Output, BTreeNode<'t, BST<I>>>
 {
     fn insert(&mut self, node: Self::Node) -> Result<Option<Self::Node>, Self::Error> {
-        let mut subtree_index: usize = usize::from(self.root_tree.allocate(&node)?).max(self.length());
+        let mut walker: BTreeWalker<C, O> = BTreeWalker::new(self.root_tree.inner())?;
+        let mut subtree_index: usize = usize::from(walker.allocate(&node)?).max(self.length());
 
         //let mut subtree: &mut BST<I> = &mut self.inner.inner_mut()[subtree_index];
         //if subtree.length() < MAX_ELEMENTS {
@@ -178,7 +197,10 @@ impl<'t, C, I> TreeWrite for BTree<C> where
         Ok(None)
     }
 
-    fn delete(&mut self, node: &Self::Node) -> Result<Self::Node, Self::Error> {
+    fn delete<Q: ?Sized>(&mut self, key: &Q) -> Result<Self::Node, Self::Error> where
+        Self::Key: Borrow<Q>,
+        Self::Order: Compare<Q>
+    {
         todo!()
     }
 
@@ -187,9 +209,9 @@ impl<'t, C, I> TreeWrite for BTree<C> where
     }
 }
 
-pub type BTreeWalker<'w, C> = BSTWalker<'w, C>;
+pub type BTreeWalker<'w, C, O = Natural> = BSTWalker<'w, C, usize, O>;
 
-impl<'w, C> BTreeWalker<'w, C> where
+impl<'w, C, O> BTreeWalker<'w, C, O> where
     C: IndexedCollection,
     C::Output: PartialOrd + Sized
 {
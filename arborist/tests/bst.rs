@@ -8,7 +8,7 @@ use arborist_core::{
     fenwick::FenwickTreeError
 };
 use arborist::bst::{
-    BSTWalker, BSTWalkerResult,
+    BSTWalker, BSTWalkerMut, BSTWalkerResult,
     BSTError
 };
 
@@ -202,14 +202,14 @@ fn bstwalker_allocate() {
     let mut index: usize = 1;
     // Skip first element as index 0 is unreachable in implementation
     for element in &collection[1..collection.len()] {
-        assert_eq!(walker.allocate(element), BSTWalkerResult::Existing(index));
+        assert_eq!(walker.allocate(element), Ok(BSTWalkerResult::Existing(index)));
         walker.reset();
 
         // Elements lt- should be inserted at the current index
         unwrap_enum!(
             walker.allocate(&(element - 1)),
             panic!("Unexpected enum result"),
-            BSTWalkerResult::New(allocated, side) => assert_eq!(allocated + side as usize, index)
+            Ok(BSTWalkerResult::New(allocated, side)) => assert_eq!(allocated + side as usize, index)
         );
         walker.reset();
 
@@ -217,7 +217,7 @@ fn bstwalker_allocate() {
         unwrap_enum!(
             walker.allocate(&(element + 1)),
             panic!("Unexpected enum result"),
-            BSTWalkerResult::New(allocated, side) => assert_eq!(allocated + side as usize, index + 1)
+            Ok(BSTWalkerResult::New(allocated, side)) => assert_eq!(allocated + side as usize, index + 1)
         );
         walker.reset();
 
@@ -243,6 +243,66 @@ fn bstwalker_find() {
     assert_eq!(walker.find(&(collection.last().unwrap() + 1)), Err(BSTError::KeyNotFound));
 }
 
+#[test]
+fn bstwalker_mut() {
+    let mut collection: [usize; BST_SIZE] = gen_collection();
+    let expected: [usize; BST_SIZE] = collection;
+    let mut walker: BSTWalkerMut<[usize; BST_SIZE]> = BSTWalkerMut::new(&mut collection).unwrap();
+
+    // Find the middle element, then bump it and everything after it in key order
+    let start: usize = BST_SIZE / 2;
+    *walker.find_mut(&expected[start]).unwrap() += 1;
+    while let Ok(value) = walker.next_mut() {
+        *value += 1;
+    }
+    assert_eq!(walker.next_mut(), Err(BSTError::Inner(FenwickTreeError::OutOfBounds { index: BST_SIZE + 1, length: BST_SIZE })));
+
+    walker.reset();
+    assert_eq!(walker.find_mut(&expected[1]).copied(), Ok(expected[1]));
+    assert_eq!(walker.prev_mut(), Err(BSTError::Inner(FenwickTreeError::OutOfBounds { index: 0, length: BST_SIZE })));
+
+    for index in 1..BST_SIZE {
+        assert_eq!(collection[index], expected[index] + (index >= start) as usize);
+    }
+}
+
+#[test]
+fn bstwalker_narrow_index() {
+    let collection: [usize; BST_SIZE] = gen_collection();
+    let mut walker: BSTWalker<[usize; BST_SIZE], u16> = BSTWalker::new(&collection).unwrap();
+
+    assert_eq!(walker.view.index(), 16u16);
+    for (index, element) in collection.iter().enumerate().skip(1) {
+        assert_eq!(walker.find(element), Ok(index as u16));
+        walker.reset();
+    }
+
+    let oversized: Vec<usize> = (0..=u16::MAX as usize + 1).collect();
+    assert_eq!(
+        BSTWalker::<Vec<usize>, u16>::new(&oversized).err(),
+        Some(BSTError::Inner(FenwickTreeError::Overflow))
+    );
+}
+
+#[test]
+fn bst_narrow_index() {
+    use arborist::bst::{BST, InsertError};
+    use arborist_core::TreeWrite;
+
+    // Slot 0 and every node must stay addressable by a u16
+    let mut bst: BST<Vec<usize>, u16> = BST::new();
+    for element in 0..u16::MAX as usize - 1 {
+        assert_eq!(bst.insert(element), Ok(None));
+    }
+
+    assert_eq!(
+        bst.try_insert(usize::MAX).err(),
+        Some(InsertError::new(BSTError::Inner(FenwickTreeError::Overflow), usize::MAX))
+    );
+    assert_eq!(bst.insert(usize::MAX), Err(BSTError::Inner(FenwickTreeError::Overflow)));
+    assert_eq!(bst.try_insert(100), Ok(Some(100)));
+}
+
 mod bstset {
     use arborist::bst::bstset::*;
     use super::*;
@@ -277,4 +337,1304 @@ mod bstmap_const {
     impl_suite!{
         BSTMapConst<usize, usize, BST_SIZE>, map
     }
-}
\ No newline at end of file
+}
+
+mod cursor {
+    use arborist::bst::bstset::*;
+    use super::*;
+
+    fn tree_of(elements: &[usize]) -> BSTSet<usize> {
+        let mut bst: BSTSet<usize> = BSTSet::new();
+        for element in elements {
+            bst.insert(*element).unwrap();
+        }
+
+        bst
+    }
+
+    #[test]
+    fn navigation() {
+        let bst: BSTSet<usize> = tree_of(&[10, 20, 30, 40]);
+
+        let mut cursor = bst.lower_bound_cursor(&25).unwrap();
+        assert_eq!(cursor.current(), Some(&30));
+        assert_eq!(cursor.index(), Some(2));
+        assert_eq!(cursor.peek_prev(), Some(&20));
+        assert_eq!(cursor.peek_next(), Some(&40));
+
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.peek_next(), Some(&10));
+        assert_eq!(cursor.peek_prev(), Some(&40));
+
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&10));
+        cursor.move_prev();
+        assert_eq!(cursor.index(), None);
+
+        assert_eq!(bst.lower_bound_cursor(&20).unwrap().current(), Some(&20));
+        assert_eq!(bst.lower_bound_cursor(&41).unwrap().current(), None);
+        assert_eq!(bst.cursor_front().current(), Some(&10));
+        assert_eq!(bst.cursor_back().current(), Some(&40));
+    }
+
+    #[test]
+    fn empty() {
+        let mut bst: BSTSet<usize> = BSTSet::new();
+        assert_eq!(bst.cursor_front().current(), None);
+        assert_eq!(bst.cursor_back().peek_next(), None);
+
+        let mut cursor = bst.cursor_front_mut();
+        assert_eq!(cursor.remove_current(), None);
+        cursor.insert_after(5).unwrap();
+        cursor.insert_before(7).unwrap();
+        assert_eq!(cursor.current(), None);
+
+        assert_eq!(bst.first(), Ok(&5));
+        assert_eq!(bst.last(), Ok(&7));
+    }
+
+    #[test]
+    fn insert() {
+        let mut bst: BSTSet<usize> = tree_of(&[10, 20, 30]);
+
+        let mut cursor = bst.lower_bound_cursor_mut(&20).unwrap();
+        cursor.insert_before(15).unwrap();
+        cursor.insert_after(25).unwrap();
+        assert_eq!(cursor.current(), Some(&20));
+        assert_eq!(cursor.peek_prev(), Some(&15));
+        assert_eq!(cursor.peek_next(), Some(&25));
+
+        assert_eq!(cursor.insert_before(20), Err(BSTError::Unordered));
+        assert_eq!(cursor.insert_before(5), Err(BSTError::Unordered));
+        assert_eq!(cursor.insert_after(30), Err(BSTError::Unordered));
+
+        for expected in [10, 15, 20, 25, 30] {
+            assert!(bst.contains(&expected).unwrap());
+        }
+        assert_eq!(bst.length(), 5);
+    }
+
+    #[test]
+    fn insert_full() {
+        let mut bst: BSTSetConst<usize, 3> = BSTSetConst::new();
+        let mut cursor = bst.cursor_front_mut();
+        cursor.insert_after(1).unwrap();
+        cursor.insert_after(0).unwrap();
+
+        assert_eq!(cursor.insert_before(2), Err(BSTError::FULL));
+    }
+
+    #[test]
+    fn remove_current() {
+        let mut bst: BSTSet<usize> = tree_of(&[10, 20, 30]);
+
+        let mut cursor = bst.lower_bound_cursor_mut(&20).unwrap();
+        assert_eq!(cursor.remove_current(), Some(20));
+        assert_eq!(cursor.current(), Some(&30));
+        assert_eq!(cursor.remove_current(), Some(30));
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.peek_next(), Some(&10));
+
+        assert_eq!(bst.length(), 1);
+        assert!(!bst.contains(&20).unwrap());
+    }
+
+    #[test]
+    fn current_value_mut() {
+        use arborist::bst::bstmap::BSTMap;
+
+        let mut bst: BSTMap<usize, char> = BSTMap::new();
+        bst.insert((1, 'a')).unwrap();
+        bst.insert((2, 'b')).unwrap();
+
+        let mut cursor = bst.lower_bound_cursor_mut(&2).unwrap();
+        *cursor.current_value_mut().unwrap() = 'c';
+        cursor.move_next();
+        assert_eq!(cursor.current_value_mut(), None);
+
+        assert_eq!(bst.as_slice(), [(1, 'a'), (2, 'c')]);
+    }
+}
+
+mod iter {
+    use core::ops::Bound;
+    use super::*;
+
+    macro_rules! impl_set_iter {
+        ($bst:ty) => {
+            use arborist::bst::bstset::*;
+            use super::*;
+
+            fn tree_of(elements: &[usize]) -> $bst {
+                let mut bst: $bst = <$bst>::new();
+                for element in elements {
+                    bst.insert(*element).unwrap();
+                }
+
+                bst
+            }
+
+            #[test]
+            fn iter() {
+                let bst: $bst = tree_of(&[30, 10, 40, 20]);
+
+                let mut iter = bst.iter();
+                assert_eq!(iter.len(), 4);
+                assert_eq!(iter.next(), Some(&10));
+                assert_eq!(iter.next_back(), Some(&40));
+                assert_eq!(iter.collect::<Vec<&usize>>(), [&20, &30]);
+
+                assert_eq!(<$bst>::new().iter().next(), None);
+            }
+
+            #[test]
+            fn into_iter_ref() {
+                let bst: $bst = tree_of(&[1, 2, 3]);
+                assert_eq!((&bst).into_iter().rev().collect::<Vec<&usize>>(), [&3, &2, &1]);
+            }
+
+            #[test]
+            fn into_iter() {
+                let bst: $bst = tree_of(&[3, 1, 2]);
+                let mut iter = bst.into_iter();
+
+                assert_eq!(iter.len(), 3);
+                assert_eq!(iter.next_back(), Some(3));
+                assert_eq!(iter.collect::<Vec<usize>>(), [1, 2]);
+            }
+
+            #[test]
+            fn range() {
+                let bst: $bst = tree_of(&[10, 20, 30, 40, 50]);
+
+                assert_eq!(bst.range(20..40).unwrap().collect::<Vec<&usize>>(), [&20, &30]);
+                assert_eq!(bst.range(15..=40).unwrap().rev().collect::<Vec<&usize>>(), [&40, &30, &20]);
+                assert_eq!(bst.range((Bound::Excluded(10), Bound::Excluded(30))).unwrap().collect::<Vec<&usize>>(), [&20]);
+                assert_eq!(bst.range(..).unwrap().len(), 5);
+                assert_eq!(bst.range(45..).unwrap().collect::<Vec<&usize>>(), [&50]);
+                assert_eq!(bst.range(..5).unwrap().next(), None);
+                assert_eq!(bst.range(60..).unwrap().next(), None);
+                assert_eq!(bst.range(40..20).unwrap().next(), None);
+
+                assert_eq!(bst.count_range(20..50), Ok(3));
+                assert_eq!(bst.count_range(..), Ok(5));
+                assert_eq!(<$bst>::new().count_range(..10), Ok(0));
+            }
+        };
+    }
+
+    macro_rules! impl_map_iter {
+        ($bst:ty) => {
+            use arborist::bst::bstmap::*;
+            use super::*;
+
+            fn tree_of(elements: &[(usize, usize)]) -> $bst {
+                let mut bst: $bst = <$bst>::new();
+                for (key, value) in elements {
+                    bst.insert(*key, *value).unwrap();
+                }
+
+                bst
+            }
+
+            #[test]
+            fn iter() {
+                let bst: $bst = tree_of(&[(3, 30), (1, 10), (2, 20)]);
+
+                let mut iter = bst.iter();
+                assert_eq!(iter.len(), 3);
+                assert_eq!(iter.next(), Some((&1, &10)));
+                assert_eq!(iter.next_back(), Some((&3, &30)));
+                assert_eq!(iter.next(), Some((&2, &20)));
+                assert_eq!(iter.next(), None);
+
+                assert_eq!(bst.keys().collect::<Vec<&usize>>(), [&1, &2, &3]);
+                assert_eq!(bst.values().rev().collect::<Vec<&usize>>(), [&30, &20, &10]);
+            }
+
+            #[test]
+            fn iter_mut() {
+                let mut bst: $bst = tree_of(&[(2, 20), (1, 10)]);
+                for (key, value) in bst.iter_mut() {
+                    *value += key;
+                }
+                for value in bst.values_mut() {
+                    *value *= 2;
+                }
+                for (_, value) in &mut bst {
+                    *value += 1;
+                }
+
+                assert_eq!(bst.values().collect::<Vec<&usize>>(), [&23, &45]);
+            }
+
+            #[test]
+            fn into_keys_values() {
+                let bst: $bst = tree_of(&[(2, 20), (1, 10)]);
+                assert_eq!(bst.clone().into_keys().collect::<Vec<usize>>(), [1, 2]);
+                assert_eq!(bst.into_values().rev().collect::<Vec<usize>>(), [20, 10]);
+            }
+
+            #[test]
+            fn range() {
+                let mut bst: $bst = tree_of(&[(1, 10), (2, 20), (3, 30), (4, 40)]);
+
+                assert_eq!(bst.range(2..4).unwrap().collect::<Vec<(&usize, &usize)>>(), [(&2, &20), (&3, &30)]);
+                assert_eq!(bst.range(3..).unwrap().next_back(), Some((&4, &40)));
+                assert_eq!(bst.range(5..).unwrap().next(), None);
+
+                for (_, value) in bst.range_mut(..=2).unwrap() {
+                    *value = 0;
+                }
+                assert_eq!(bst.values().collect::<Vec<&usize>>(), [&0, &0, &30, &40]);
+
+                assert_eq!(bst.count_range(2..=3), Ok(2));
+                assert_eq!(bst.count_range(..), Ok(4));
+            }
+        };
+    }
+
+    mod bstset {
+        impl_set_iter!{BSTSet<usize>}
+    }
+
+    mod bstset_const {
+        impl_set_iter!{BSTSetConst<usize, BST_SIZE>}
+    }
+
+    mod bstmap {
+        impl_map_iter!{BSTMap<usize, usize>}
+    }
+
+    mod bstmap_const {
+        impl_map_iter!{BSTMapConst<usize, usize, BST_SIZE>}
+    }
+}
+
+mod ordered {
+    use super::*;
+
+    #[test]
+    fn bstset() {
+        use arborist::bst::bstset::*;
+
+        let mut bst: BSTSet<usize> = BSTSet::new();
+        assert_eq!(bst.floor(&10), Err(BSTError::KeyNotFound));
+
+        for element in [10, 20, 30] {
+            bst.insert(element).unwrap();
+        }
+
+        assert_eq!(bst.floor(&20), Ok(&20));
+        assert_eq!(bst.floor(&25), Ok(&20));
+        assert_eq!(bst.floor(&5), Err(BSTError::KeyNotFound));
+        assert_eq!(bst.ceiling(&20), Ok(&20));
+        assert_eq!(bst.ceiling(&15), Ok(&20));
+        assert_eq!(bst.ceiling(&35), Err(BSTError::KeyNotFound));
+
+        assert_eq!(bst.lower(&20), Ok(&10));
+        assert_eq!(bst.lower(&10), Err(BSTError::KeyNotFound));
+        assert_eq!(bst.higher(&20), Ok(&30));
+        assert_eq!(bst.higher(&30), Err(BSTError::KeyNotFound));
+        assert_eq!(bst.higher(&0), Ok(&10));
+    }
+
+    #[test]
+    fn bstmap() {
+        use arborist::bst::bstmap::*;
+
+        let mut bst: BSTMap<usize, usize> = BSTMap::new();
+        for key in [1, 3, 5] {
+            bst.insert(key, key * 10).unwrap();
+        }
+
+        assert_eq!(bst.floor(&4), Ok((&3, &30)));
+        assert_eq!(bst.ceiling(&4), Ok((&5, &50)));
+        assert_eq!(bst.lower(&3), Ok((&1, &10)));
+        assert_eq!(bst.higher(&3), Ok((&5, &50)));
+        assert_eq!(bst.higher(&5), Err(BSTError::KeyNotFound));
+    }
+}
+
+mod rank {
+    use super::*;
+
+    #[test]
+    fn bstset() {
+        use arborist::bst::bstset::*;
+
+        let mut bst: BSTSet<usize> = BSTSet::new();
+        assert_eq!(bst.median(), None);
+        assert_eq!(bst.percentile(50.0), None);
+
+        for element in [50, 10, 40, 20, 30] {
+            bst.insert(element).unwrap();
+        }
+
+        assert_eq!(bst.rank(&10), Ok(0));
+        assert_eq!(bst.rank(&35), Ok(3));
+        assert_eq!(bst.rank(&99), Ok(5));
+        assert_eq!(bst.select(1), Some(&20));
+        assert_eq!(bst.get_index(4), Some(&50));
+        assert_eq!(bst.select(5), None);
+        assert_eq!(bst.index_of(&40), Ok(Some(3)));
+        assert_eq!(bst.index_of(&35), Ok(None));
+
+        assert_eq!(bst.median(), Some(&30));
+        assert_eq!(bst.percentile(0.0), Some(&10));
+        assert_eq!(bst.percentile(40.0), Some(&20));
+        assert_eq!(bst.percentile(100.0), Some(&50));
+
+        assert_eq!(bst.remove_index(1), Some(20));
+        assert_eq!(bst.remove_index(4), None);
+        assert_eq!(bst.median(), Some(&30));
+        assert_eq!(bst.select(1), Some(&30));
+    }
+
+    #[test]
+    fn bstmap() {
+        use arborist::bst::bstmap::*;
+
+        let mut bst: BSTMap<usize, usize> = BSTMap::new();
+        for key in [3, 1, 2, 4] {
+            bst.insert(key, key * 10).unwrap();
+        }
+
+        assert_eq!(bst.rank(&3), Ok(2));
+        assert_eq!(bst.select(0), Some((&1, &10)));
+        assert_eq!(bst.get_index(3), Some((&4, &40)));
+        assert_eq!(bst.index_of(&2), Ok(Some(1)));
+        assert_eq!(bst.median(), Some((&2, &20)));
+        assert_eq!(bst.percentile(75.0), Some((&3, &30)));
+
+        assert_eq!(bst.remove_index(0), Some((1, 10)));
+        assert_eq!(bst.select(0), Some((&2, &20)));
+    }
+}
+
+mod entry {
+    use arborist::bst::bstmap::*;
+    use super::*;
+
+    #[test]
+    fn or_insert() {
+        let mut bst: BSTMap<usize, usize> = BSTMap::new();
+
+        *bst.entry(2).unwrap().or_insert(20).unwrap() += 1;
+        *bst.entry(2).unwrap().or_insert(0).unwrap() += 1;
+        assert_eq!(bst.entry(1).unwrap().or_insert_with(|| 10), Ok(&mut 10));
+        assert_eq!(bst.entry(3).unwrap().or_default(), Ok(&mut 0));
+        assert_eq!(bst.entry(4).unwrap().key(), &4);
+
+        assert_eq!(bst.iter().collect::<Vec<(&usize, &usize)>>(), [(&1, &10), (&2, &22), (&3, &0)]);
+    }
+
+    #[test]
+    fn and_modify() {
+        let mut bst: BSTMap<usize, usize> = BSTMap::new();
+
+        for _ in 0..3 {
+            bst.entry(7).unwrap().and_modify(| count | *count += 1).or_insert(1).unwrap();
+        }
+        assert_eq!(bst.get(&7), Ok(&3));
+    }
+
+    #[test]
+    fn occupied_and_vacant() {
+        let mut bst: BSTMap<usize, usize> = BSTMap::new();
+        bst.insert(1, 10).unwrap();
+        bst.insert(3, 30).unwrap();
+
+        match bst.entry(1).unwrap() {
+            Entry::Occupied(mut entry) => {
+                assert_eq!(entry.get(), &10);
+                assert_eq!(entry.insert(11), 10);
+                assert_eq!(entry.remove(), 11);
+            },
+            Entry::Vacant(_) => panic!("Expected an occupied entry")
+        }
+
+        match bst.entry(2).unwrap() {
+            Entry::Vacant(entry) => assert_eq!(entry.insert(20), Ok(&mut 20)),
+            Entry::Occupied(_) => panic!("Expected a vacant entry")
+        }
+
+        match bst.entry(3).unwrap() {
+            Entry::Occupied(entry) => assert_eq!(entry.remove_entry(), (3, 30)),
+            Entry::Vacant(_) => panic!("Expected an occupied entry")
+        }
+
+        assert_eq!(bst.keys().collect::<Vec<&usize>>(), [&2]);
+    }
+
+    #[test]
+    fn full() {
+        let mut bst: BSTMapConst<usize, usize, 2> = BSTMapConst::new();
+        bst.entry(1).unwrap().or_insert(10).unwrap();
+
+        assert_eq!(bst.entry(2).unwrap().or_insert(20), Err(BSTError::FULL));
+        assert_eq!(bst.entry(1).unwrap().or_insert(0), Ok(&mut 10));
+    }
+}
+
+mod borrow {
+    use super::*;
+    use core::ops::Bound;
+
+    #[test]
+    fn bstset() {
+        use arborist::bst::bstset::*;
+
+        let mut bst: BSTSet<String> = BSTSet::new();
+        for element in ["b", "a", "c"] {
+            bst.insert(element.to_string()).unwrap();
+        }
+
+        assert_eq!(bst.contains("b"), Ok(true));
+        assert_eq!(bst.contains("d"), Ok(false));
+        assert_eq!(bst.get("c").map(String::as_str), Ok("c"));
+        assert_eq!(bst.ceiling("aa").map(String::as_str), Ok("b"));
+        assert_eq!(bst.delete("a"), Ok("a".to_string()));
+        assert_eq!(bst.rank("c"), Ok(1));
+    }
+
+    #[test]
+    fn bstmap() {
+        use arborist::bst::bstmap::*;
+
+        let mut bst: BSTMap<String, usize> = BSTMap::new();
+        for (value, key) in ["one", "two", "three"].into_iter().enumerate() {
+            bst.insert(key.to_string(), value).unwrap();
+        }
+
+        assert_eq!(bst.get("two"), Ok(&1));
+        assert_eq!(bst.get_key_value("three"), Ok((&"three".to_string(), &2)));
+        assert_eq!(bst.get("four"), Err(BSTError::KeyNotFound));
+        assert_eq!(bst.contains("one"), Ok(true));
+
+        *bst.get_mut("one").unwrap() += 10;
+        assert_eq!(bst.delete("one"), Ok(10));
+        assert_eq!(bst.range::<str, _>((Bound::Included("t"), Bound::Unbounded)).map(Iterator::count), Ok(2));
+    }
+}
+
+mod compare {
+    use super::*;
+    use core::cmp::Ordering;
+    use arborist_core::{Compare, NodeKey, Reverse, ByKey, TotalOrder};
+
+    #[test]
+    fn reverse() {
+        use arborist::bst::bstset::*;
+
+        let mut bst: BSTSet<usize, Reverse> = BSTSet::new();
+        for element in [2, 3, 1] {
+            bst.insert(element).unwrap();
+        }
+
+        assert_eq!(bst.iter().collect::<Vec<&usize>>(), [&3, &2, &1]);
+        assert_eq!(bst.first(), Ok(&3));
+        assert_eq!(bst.ceiling(&4), Ok(&3));
+        assert_eq!(bst.higher(&2), Ok(&1));
+        assert_eq!(bst.delete(&2), Ok(2));
+    }
+
+    #[test]
+    fn total_order() {
+        use arborist::bst::bstset::*;
+
+        let mut bst: BSTSet<f64, TotalOrder> = BSTSet::new();
+        for element in [f64::NAN, 1.0, -0.0, 0.0, f64::NEG_INFINITY] {
+            assert_eq!(bst.insert(element), Ok(None));
+        }
+
+        assert_eq!(bst.length(), 5);
+        assert_eq!(bst.first(), Ok(&f64::NEG_INFINITY));
+        assert!(bst.last().unwrap().is_nan());
+        assert_eq!(bst.contains(&f64::NAN), Ok(true));
+        assert_eq!(bst.rank(&0.0), Ok(2));
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Default)]
+    struct Job {
+        id: usize,
+        priority: isize
+    }
+
+    struct Priority;
+
+    impl NodeKey<Job> for Priority {
+        type Key = isize;
+
+        fn key(job: &Job) -> &isize {
+            &job.priority
+        }
+    }
+
+    #[test]
+    fn by_key() {
+        use arborist::bst::bstset::*;
+
+        let mut bst: BSTSetConst<Job, 4, ByKey<Priority, Reverse>> = BSTSetConst::new();
+        for (id, priority) in [(0, 5), (1, -1), (2, 10)] {
+            bst.insert(Job { id, priority }).unwrap();
+        }
+
+        assert_eq!(bst.iter().map(| job | job.id).collect::<Vec<usize>>(), [2, 0, 1]);
+        assert_eq!(bst.insert(Job { id: 3, priority: 5 }), Ok(Some(Job { id: 0, priority: 5 })));
+    }
+
+    struct CaseInsensitive;
+
+    impl Compare<str> for CaseInsensitive {
+        fn compare(lhs: &str, rhs: &str) -> Option<Ordering> {
+            Some(lhs.bytes().map(| byte | byte.to_ascii_lowercase()).cmp(rhs.bytes().map(| byte | byte.to_ascii_lowercase())))
+        }
+    }
+
+    impl Compare<String> for CaseInsensitive {
+        fn compare(lhs: &String, rhs: &String) -> Option<Ordering> {
+            <Self as Compare<str>>::compare(lhs, rhs)
+        }
+    }
+
+    #[test]
+    fn custom() {
+        use arborist::bst::bstmap::*;
+
+        let mut bst: BSTMap<String, usize, CaseInsensitive> = BSTMap::new();
+        bst.insert("Beta".to_string(), 1).unwrap();
+        bst.insert("alpha".to_string(), 0).unwrap();
+
+        assert_eq!(bst.insert("BETA".to_string(), 2), Ok(Some(1)));
+        assert_eq!(bst.get("ALPHA"), Ok(&0));
+        assert_eq!(bst.keys().collect::<Vec<&String>>(), ["alpha", "BETA"]);
+    }
+
+    #[test]
+    fn walker() {
+        let elements: Vec<usize> = vec![0, 3, 2, 1];
+        let mut walker: BSTWalker<Vec<usize>, usize, Reverse> = BSTWalker::new(&elements).unwrap();
+
+        assert_eq!(walker.find(&2), Ok(2));
+        assert_eq!(walker.find(&4), Err(BSTError::KeyNotFound));
+    }
+}
+
+mod incomparable {
+    use super::*;
+    use arborist_core::TotalF64;
+
+    #[test]
+    fn nan() {
+        use arborist::bst::bstset::*;
+
+        let mut bst: BSTSet<f64> = BSTSet::new();
+        for element in [1.0, 2.0, 3.0] {
+            bst.insert(element).unwrap();
+        }
+
+        assert_eq!(bst.insert(f64::NAN), Err(BSTError::Incomparable));
+        assert_eq!(bst.get(&f64::NAN), Err(BSTError::Incomparable));
+        assert_eq!(bst.contains(&f64::NAN), Err(BSTError::Incomparable));
+        assert_eq!(bst.delete(&f64::NAN), Err(BSTError::Incomparable));
+        assert_eq!(bst.contains(&4.0), Ok(false));
+        assert_eq!(bst.length(), 3);
+    }
+
+    #[test]
+    fn total_f64() {
+        use arborist::bst::bstmap::*;
+
+        let mut bst: BSTMap<TotalF64, usize> = BSTMap::new();
+        for (value, key) in [1.0, f64::NAN, -0.0, 0.0].into_iter().enumerate() {
+            assert_eq!(bst.insert(TotalF64(key), value), Ok(None));
+        }
+
+        assert_eq!(bst.get(&TotalF64(f64::NAN)), Ok(&1));
+        assert_eq!(bst.values().collect::<Vec<&usize>>(), [&2, &3, &0, &1]);
+    }
+}
+
+mod multi {
+    use super::*;
+
+    #[test]
+    fn bstmultiset() {
+        use arborist::bst::bstset::*;
+
+        let mut bst: BSTMultiSet<usize> = BSTMultiSet::new();
+        for element in [3, 1, 3, 2, 3, 1] {
+            assert_eq!(bst.insert(element), Ok(None));
+        }
+
+        assert_eq!(bst.iter().collect::<Vec<&usize>>(), [&1, &1, &2, &3, &3, &3]);
+        assert_eq!(bst.count(&3), Ok(3));
+        assert_eq!(bst.count(&4), Ok(0));
+        assert_eq!(bst.get_all(&1), Ok(&[1, 1][..]));
+        assert_eq!(bst.get_all(&0), Ok(&[][..]));
+        assert_eq!(bst.rank(&3), Ok(3));
+        assert_eq!(bst.index_of(&3), Ok(Some(3)));
+        assert_eq!(bst.count_range(1..3), Ok(3));
+
+        assert_eq!(bst.remove_one(&1), Ok(1));
+        assert_eq!(bst.remove_one(&4), Err(BSTError::KeyNotFound));
+        assert_eq!(bst.remove_all(&3), Ok(3));
+        assert_eq!(bst.remove_all(&3), Ok(0));
+        assert_eq!(bst.iter().collect::<Vec<&usize>>(), [&1, &2]);
+    }
+
+    #[test]
+    fn bstmultimap() {
+        use arborist::bst::bstmap::*;
+
+        let mut bst: BSTMultiMap<usize, char> = BSTMultiMap::new();
+        for (key, value) in [(2, 'a'), (1, 'b'), (2, 'c'), (2, 'd')] {
+            assert_eq!(bst.insert(key, value), Ok(None));
+        }
+
+        // Equal keys keep their insertion order
+        assert_eq!(bst.get_all(&2), Ok(&[(2, 'a'), (2, 'c'), (2, 'd')][..]));
+        assert_eq!(bst.get(&2), Ok(&'a'));
+        assert_eq!(bst.delete(&2), Ok('a'));
+        assert_eq!(bst.remove_one(&2), Ok((2, 'c')));
+        assert_eq!(bst.values().collect::<Vec<&char>>(), [&'b', &'d']);
+    }
+
+    #[test]
+    fn get_mut() {
+        use arborist::bst::bstmap::*;
+
+        let mut bst: BSTMultiMap<usize, usize> = BSTMultiMap::new();
+        for value in 0..8 {
+            bst.insert(value % 2, value).unwrap();
+        }
+
+        // Agrees with `get` on the first of the run
+        for key in [0, 1] {
+            let mut first: usize = *bst.get(&key).unwrap();
+            assert_eq!(bst.get_mut(&key), Ok(&mut first));
+
+            *bst.get_mut(&key).unwrap() += 10;
+            assert_eq!(bst.get(&key), Ok(&(first + 10)));
+        }
+        assert_eq!(bst.values().collect::<Vec<&usize>>(), [&10, &2, &4, &6, &11, &3, &5, &7]);
+    }
+
+    #[test]
+    fn full() {
+        use arborist::bst::bstset::*;
+
+        let mut bst: BSTMultiSetConst<usize, 3> = BSTMultiSetConst::new();
+        assert_eq!(bst.insert(1), Ok(None));
+        assert_eq!(bst.insert(1), Ok(None));
+        assert_eq!(bst.insert(1), Err(BSTError::FULL));
+        assert_eq!(bst.count(&1), Ok(2));
+    }
+}
+
+mod bulk {
+    use super::*;
+
+    #[test]
+    fn from_sorted_iter() {
+        use arborist::bst::bstset::*;
+
+        let bst: BSTSet<usize> = BSTSet::from_sorted_iter(0..100, Dedup::Reject).unwrap();
+        assert_eq!(bst.length(), 100);
+        assert_eq!(bst.get(&42), Ok(&42));
+        assert!(bst.iter().copied().eq(0..100));
+
+        assert_eq!(BSTSet::<usize>::from_sorted_iter([1, 3, 2], Dedup::Reject).err(), Some(BSTError::Unordered));
+        assert_eq!(BSTSet::<usize>::from_sorted_iter([1, 1], Dedup::Reject).err(), Some(BSTError::Unordered));
+        assert_eq!(BSTSet::<f64>::from_sorted_iter([1.0, f64::NAN], Dedup::Reject).err(), Some(BSTError::Incomparable));
+        assert_eq!(
+            BSTSetConst::<usize, 4>::from_sorted_iter(0..4, Dedup::Reject).err(),
+            Some(BSTError::FULL)
+        );
+    }
+
+    #[test]
+    fn dedup() {
+        use arborist::bst::bstmap::*;
+
+        let sorted = [(1, 'a'), (1, 'b'), (2, 'c'), (2, 'd')];
+        let first: BSTMap<usize, char> = BSTMap::from_sorted_iter(sorted, Dedup::KeepFirst).unwrap();
+        let last: BSTMapConst<usize, char, 4> = BSTMapConst::from_sorted_iter(sorted, Dedup::KeepLast).unwrap();
+        assert_eq!(first.values().collect::<Vec<&char>>(), [&'a', &'c']);
+        assert_eq!(last.values().collect::<Vec<&char>>(), [&'b', &'d']);
+
+        let unsorted = [(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd'), (0, 'e')];
+        let first: BSTMap<usize, char> = BSTMap::from_unsorted(unsorted, Dedup::KeepFirst).unwrap();
+        let last: BSTMap<usize, char> = BSTMap::from_unsorted(unsorted, Dedup::KeepLast).unwrap();
+        let multi: BSTMultiMap<usize, char> = BSTMultiMap::from_unsorted(unsorted, Dedup::Reject).unwrap();
+        assert_eq!(first.iter().collect::<Vec<(&usize, &char)>>(), [(&0, &'e'), (&1, &'b'), (&2, &'a')]);
+        assert_eq!(last.iter().collect::<Vec<(&usize, &char)>>(), [(&0, &'e'), (&1, &'d'), (&2, &'c')]);
+        assert_eq!(multi.get_all(&2), Ok(&[(2, 'a'), (2, 'c')][..]));
+        assert_eq!(BSTMap::<usize, char>::from_unsorted(unsorted, Dedup::Reject).err(), Some(BSTError::Unordered));
+    }
+
+    #[test]
+    fn from_iter() {
+        use arborist::bst::bstset::*;
+
+        let mut gen: RandomArgs = RandomArgs::new();
+        let elements: Vec<usize> = (0..BST_SIZE).map(| _ | {
+            gen.next();
+            gen.arg()
+        }).collect();
+
+        let collected: BSTSet<usize> = elements.iter().copied().collect();
+        let mut inserted: BSTSet<usize> = BSTSet::new();
+        for element in &elements {
+            inserted.insert(*element).unwrap();
+        }
+
+        assert_eq!(collected.as_slice(), inserted.as_slice());
+    }
+
+    #[test]
+    fn extend() {
+        use arborist::bst::bstmap::*;
+
+        let mut bst: BSTMap<usize, char> = [(1, 'a'), (3, 'b'), (5, 'c')].into_iter().collect();
+        bst.extend([(4, 'd'), (3, 'e'), (0, 'f'), (6, 'g')]);
+        assert_eq!(
+            bst.iter().collect::<Vec<(&usize, &char)>>(),
+            [(&0, &'f'), (&1, &'a'), (&3, &'e'), (&4, &'d'), (&5, &'c'), (&6, &'g')]
+        );
+        assert_eq!(bst.get(&4), Ok(&'d'));
+    }
+
+    #[test]
+    fn try_extend() {
+        use arborist::bst::bstset::*;
+
+        let mut bst: BSTSetConst<usize, 4> = BSTSetConst::new();
+        assert_eq!((bst.insert(1), bst.insert(2)), (Ok(None), Ok(None)));
+        assert_eq!(bst.try_extend([2, 3, 4]), Err(BSTError::FULL));
+        assert_eq!(bst.as_slice(), [1, 2]);
+        assert_eq!(bst.try_extend([2, 3]), Ok(()));
+        assert_eq!(bst.as_slice(), [1, 2, 3]);
+
+        let mut bst: BSTSet<f64> = BSTSet::new();
+        assert_eq!((bst.insert(1.0), bst.insert(2.0)), (Ok(None), Ok(None)));
+        assert_eq!(bst.try_extend([f64::NAN]), Err(BSTError::Incomparable));
+        assert_eq!(bst.as_slice(), [1.0, 2.0]);
+
+        use arborist::bst::bstmap::*;
+
+        // Staged in place, and the last of equal incoming nodes still wins
+        let mut bst: BSTMapConst<usize, char, 4> = BSTMapConst::new();
+        assert_eq!(TreeWriteKV::insert(&mut bst, 1, 'a'), Ok(None));
+        assert_eq!(bst.try_extend([(2, 'b'), (1, 'c'), (2, 'd')]), Ok(()));
+        assert_eq!(bst.as_slice(), [(1, 'c'), (2, 'd')]);
+    }
+}
+
+mod algebra {
+    use arborist::bst::bstset::*;
+    use super::*;
+
+    fn tree_of<T: TreeWrite>(mut tree: T, nodes: impl IntoIterator<Item = T::Node>) -> T where
+        T::Error: core::fmt::Debug
+    {
+        for node in nodes {
+            tree.insert(node).unwrap();
+        }
+
+        tree
+    }
+
+    fn sets() -> (BSTSet<usize>, BSTSetConst<usize, 8>) {
+        (tree_of(BSTSet::new(), [1, 2, 3, 5, 8]), tree_of(BSTSetConst::new(), [2, 4, 5, 6]))
+    }
+
+    #[test]
+    fn operations() {
+        let (left, right) = sets();
+
+        assert_eq!(left.union(&right).collect::<Vec<&usize>>(), [&1, &2, &3, &4, &5, &6, &8]);
+        assert_eq!(left.intersection(&right).collect::<Vec<&usize>>(), [&2, &5]);
+        assert_eq!(right.intersection(&left).collect::<Vec<&usize>>(), [&2, &5]);
+        assert_eq!(left.difference(&right).collect::<Vec<&usize>>(), [&1, &3, &8]);
+        assert_eq!(right.difference(&left).collect::<Vec<&usize>>(), [&4, &6]);
+        assert_eq!(left.symmetric_difference(&right).collect::<Vec<&usize>>(), [&1, &3, &4, &6, &8]);
+
+        let empty: BSTSet<usize> = BSTSet::new();
+        assert_eq!(left.union(&empty).count(), 5);
+        assert_eq!(empty.intersection(&left).next(), None);
+    }
+
+    #[test]
+    fn relations() {
+        let (left, right) = sets();
+        let subset: BSTSet<usize> = tree_of(BSTSet::new(), [2, 5]);
+        let empty: BSTSet<usize> = BSTSet::new();
+
+        assert!(subset.is_subset(&left) && subset.is_subset(&right));
+        assert!(left.is_superset(&subset));
+        assert!(!left.is_subset(&right));
+        assert!(!left.is_disjoint(&right));
+        assert!(empty.is_subset(&left) && empty.is_disjoint(&left));
+        assert!(left.difference(&subset).all(| element | !subset.contains(element).unwrap()));
+    }
+
+    #[test]
+    fn append() {
+        let (mut left, mut right) = sets();
+
+        assert_eq!(left.append(&mut right), Ok(()));
+        assert_eq!(left.as_slice(), [1, 2, 3, 4, 5, 6, 8]);
+        assert_eq!(right.length(), 0);
+
+        let mut full: BSTSetConst<usize, 4> = tree_of(BSTSetConst::new(), [1, 2]);
+        let mut other: BSTSet<usize> = tree_of(BSTSet::new(), [3, 4]);
+        assert_eq!(full.append(&mut other), Err(BSTError::FULL));
+        assert_eq!((full.as_slice(), other.as_slice()), (&[1, 2][..], &[3, 4][..]));
+    }
+
+    #[test]
+    fn append_multi() {
+        use arborist::bst::bstmap::{BSTMap, BSTMultiMap};
+
+        let mut map: BSTMap<usize, char> = tree_of(BSTMap::new(), [(1, 'a'), (3, 'b')]);
+        let mut multi: BSTMultiMap<usize, char> = tree_of(
+            BSTMultiMap::new(), [(1, 'c'), (2, 'd'), (2, 'e'), (2, 'f'), (3, 'g'), (3, 'h')]
+        );
+
+        assert_eq!(map.append(&mut multi), Ok(()));
+        assert_eq!(map.as_slice(), [(1, 'c'), (2, 'f'), (3, 'h')]);
+        assert_eq!(multi.length(), 0);
+
+        let mut set: BSTSet<usize> = tree_of(BSTSet::new(), [2, 4]);
+        let mut multi: BSTMultiSet<usize> = tree_of(BSTMultiSet::new(), [1, 2, 2, 3, 3, 3]);
+        assert_eq!(set.append(&mut multi), Ok(()));
+        assert_eq!(set.as_slice(), [1, 2, 3, 4]);
+
+        let left: BSTMultiSet<usize> = tree_of(BSTMultiSet::new(), [1, 1, 2]);
+        let right: BSTMultiSet<usize> = tree_of(BSTMultiSet::new(), [2, 2, 3]);
+        let merged: BSTSet<usize> = BSTSet::merge(left, right).ok().unwrap();
+        assert_eq!(merged.as_slice(), [1, 2, 3]);
+    }
+
+    #[test]
+    fn merge() {
+        let (left, right) = sets();
+
+        let merged: BSTSet<usize> = BSTSet::merge(left, right).ok().unwrap();
+        assert_eq!(merged.as_slice(), [1, 2, 3, 4, 5, 6, 8]);
+        assert_eq!(merged.get(&6), Ok(&6));
+
+        // Both sources come back untouched when the merge doesn't fit
+        let (left, right) = sets();
+        let err = BSTSetConst::<usize, 4>::merge(left, right).err().unwrap();
+        assert_eq!(err.kind, BSTError::FULL);
+
+        let (left, right) = err.into_value();
+        assert_eq!((left.as_slice(), right.as_slice()), (&[1, 2, 3, 5, 8][..], &[2, 4, 5, 6][..]));
+    }
+}
+
+mod split {
+    use arborist::bst::bstmap::*;
+    use super::*;
+
+    fn pairs<'a, T>(entries: T) -> Vec<(usize, &'a str)> where
+        T: Iterator<Item = (&'a usize, &'a &'a str)>
+    {
+        entries.map(| (key, value) | (*key, *value)).collect()
+    }
+
+    fn map_of(entries: &[(usize, &'static str)]) -> BSTMap<usize, &'static str> {
+        let mut bst: BSTMap<usize, &str> = BSTMap::new();
+        for (key, value) in entries {
+            bst.insert(*key, *value).unwrap();
+        }
+
+        bst
+    }
+
+    fn const_map_of<const N: usize>(entries: &[(usize, &'static str)]) -> BSTMapConst<usize, &'static str, N> {
+        let mut bst: BSTMapConst<usize, &str, N> = BSTMapConst::new();
+        for (key, value) in entries {
+            bst.insert(*key, *value).unwrap();
+        }
+
+        bst
+    }
+
+    fn shard() -> BSTMap<usize, &'static str> {
+        map_of(&[(1, "a"), (3, "b"), (5, "c"), (7, "d")])
+    }
+
+    #[test]
+    fn split_off() {
+        let mut left: BSTMap<usize, &str> = shard();
+        let right: BSTMap<usize, &str> = left.split_off(&4).unwrap();
+        assert_eq!(pairs(left.iter()), [(1, "a"), (3, "b")]);
+        assert_eq!(pairs(right.iter()), [(5, "c"), (7, "d")]);
+        assert_eq!(right.get(&7), Ok(&"d"));
+        assert_eq!(left.get(&5), Err(BSTError::KeyNotFound));
+
+        // Equal keys go right
+        let mut left: BSTMap<usize, &str> = shard();
+        assert_eq!(pairs(left.split_off(&3).unwrap().iter()), [(3, "b"), (5, "c"), (7, "d")]);
+        assert_eq!(pairs(left.iter()), [(1, "a")]);
+
+        let mut left: BSTMap<usize, &str> = shard();
+        assert_eq!(left.split_off(&0).unwrap().length(), 4);
+        assert_eq!(left.length(), 0);
+        assert_eq!(left.insert(2, "e"), Ok(None));
+
+        let mut left: BSTMapConst<usize, &str, 8> = const_map_of(&[(1, "a"), (3, "b")]);
+        assert_eq!(left.split_off(&9).unwrap().length(), 0);
+        assert_eq!(pairs(left.iter()), [(1, "a"), (3, "b")]);
+    }
+
+    #[test]
+    fn split_at_rank() {
+        let mut left: BSTMapConst<usize, &str, 8> = const_map_of(&[(1, "a"), (3, "b"), (5, "c")]);
+        let mut right: BSTMapConst<usize, &str, 8> = left.split_at_rank(1);
+        assert_eq!(pairs(left.iter()), [(1, "a")]);
+        assert_eq!(pairs(right.iter()), [(3, "b"), (5, "c")]);
+        assert_eq!(right.insert(4, "d"), Ok(None));
+        assert_eq!(pairs(right.iter()), [(3, "b"), (4, "d"), (5, "c")]);
+
+        assert_eq!(left.split_at_rank(5).length(), 0);
+        assert_eq!(pairs(left.split_at_rank(0).iter()), [(1, "a")]);
+        assert_eq!(left.length(), 0);
+    }
+
+    #[test]
+    fn concat() {
+        let mut left: BSTMap<usize, &str> = shard();
+        let right: BSTMap<usize, &str> = left.split_off(&5).unwrap();
+
+        let joined: BSTMap<usize, &str> = BSTMap::concat(left, right).ok().unwrap();
+        assert_eq!(pairs(joined.iter()), pairs(shard().iter()));
+        assert_eq!(joined.get(&5), Ok(&"c"));
+
+        assert_eq!(BSTMap::concat(shard(), BSTMap::new()).ok().unwrap().length(), 4);
+        assert_eq!(BSTMap::concat(BSTMap::new(), shard()).ok().unwrap().length(), 4);
+    }
+
+    #[test]
+    fn concat_hands_back_inputs() {
+        let overlapping: BSTMap<usize, &str> = map_of(&[(7, "e"), (9, "f")]);
+        let err = BSTMap::concat(shard(), overlapping).err().unwrap();
+        assert_eq!(err.kind, BSTError::Unordered);
+
+        let (left, right) = err.into_value();
+        assert_eq!(pairs(left.iter()), pairs(shard().iter()));
+        assert_eq!(pairs(right.iter()), [(7, "e"), (9, "f")]);
+
+        let left: BSTMapConst<usize, &str, 4> = const_map_of(&[(1, "a"), (2, "b")]);
+        let right: BSTMapConst<usize, &str, 4> = const_map_of(&[(3, "c"), (4, "d")]);
+        let err = BSTMapConst::concat(left, right).err().unwrap();
+        assert_eq!(err.kind, BSTError::FULL);
+
+        let (left, right) = err.into_value();
+        assert_eq!(pairs(left.iter()), [(1, "a"), (2, "b")]);
+        assert_eq!(pairs(right.iter()), [(3, "c"), (4, "d")]);
+    }
+}
+
+mod drain {
+    use arborist::bst::bstset::*;
+    use arborist::bst::bstmap::BSTMultiMap;
+    use arborist::bst::Drain;
+    use super::*;
+
+    fn set() -> BSTSet<usize> {
+        let mut bst: BSTSet<usize> = BSTSet::new();
+        for element in 0..10 {
+            bst.insert(element).unwrap();
+        }
+
+        bst
+    }
+
+    #[test]
+    fn retain() {
+        let mut bst: BSTSet<usize> = set();
+        bst.retain(| element | element % 3 == 0);
+        assert_eq!(bst.as_slice(), [0, 3, 6, 9]);
+        assert_eq!(bst.insert(4), Ok(None));
+        assert_eq!(bst.as_slice(), [0, 3, 4, 6, 9]);
+
+        let mut bst: BSTSetConst<String, 4> = BSTSetConst::new();
+        for element in ["a", "b", "c"] {
+            bst.insert(String::from(element)).unwrap();
+        }
+
+        bst.retain(| element | element != "b");
+        assert_eq!(bst.as_slice(), ["a", "c"]);
+        bst.retain(| _ | false);
+        assert_eq!(bst.length(), 0);
+    }
+
+    #[test]
+    fn retain_panicking() {
+        let mut bst: BSTSet<usize> = set();
+        let outcome = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            bst.retain(| element | match element {
+                5 => panic!("Stop retaining"),
+                element => element % 2 == 0
+            });
+        }));
+
+        // Nodes `keep` never reached stay, after those it did
+        assert!(outcome.is_err());
+        assert_eq!(bst.as_slice(), [0, 2, 4, 5, 6, 7, 8, 9]);
+        assert_eq!(bst.get(&7), Ok(&7));
+        assert_eq!(bst.insert(3), Ok(None));
+        assert_eq!(bst.as_slice(), [0, 2, 3, 4, 5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn drain() {
+        let mut bst: BSTSet<usize> = set();
+        assert_eq!(bst.drain().collect::<Vec<usize>>(), (0..10).collect::<Vec<usize>>());
+        assert_eq!(bst.length(), 0);
+        assert_eq!(bst.insert(1), Ok(None));
+
+        // Dropping a partially consumed drain still empties the tree
+        let mut bst: BSTSetConst<usize, 8> = BSTSetConst::new();
+        for element in 0..5 {
+            bst.insert(element).unwrap();
+        }
+
+        assert_eq!(bst.drain().next(), Some(0));
+        assert_eq!(bst.length(), 0);
+    }
+
+    #[test]
+    fn drain_range() {
+        let mut bst: BSTSet<usize> = set();
+        let drained: Drain<'_, Vec<usize>> = bst.drain_range(3..7).unwrap();
+        assert_eq!(drained.len(), 4);
+        assert_eq!(drained.collect::<Vec<usize>>(), [3, 4, 5, 6]);
+        assert_eq!(bst.as_slice(), [0, 1, 2, 7, 8, 9]);
+        assert_eq!(bst.get(&7), Ok(&7));
+
+        assert_eq!(bst.drain_range(8..).unwrap().collect::<Vec<usize>>(), [8, 9]);
+        assert_eq!(bst.drain_range(..=0).unwrap().collect::<Vec<usize>>(), [0]);
+        assert_eq!(bst.drain_range(5..3).unwrap().count(), 0);
+        assert_eq!(bst.as_slice(), [1, 2, 7]);
+    }
+
+    #[test]
+    fn extract_if() {
+        let mut bst: BSTSet<usize> = set();
+        let extracted: Vec<usize> = bst.extract_if(| element | [1, 2, 4, 5, 8].contains(element)).collect();
+        assert_eq!(extracted, [1, 2, 4, 5, 8]);
+        assert_eq!(bst.as_slice(), [0, 3, 6, 7, 9]);
+
+        let mut multi: BSTMultiMap<usize, char> = BSTMultiMap::new();
+        for node in [(1, 'a'), (1, 'b'), (2, 'c'), (2, 'd'), (3, 'e')] {
+            multi.insert(node).unwrap();
+        }
+
+        let extracted: Vec<(usize, char)> = multi.extract_if(| (_, value) | *value != 'c').collect();
+        assert_eq!(extracted, [(1, 'a'), (1, 'b'), (2, 'd'), (3, 'e')]);
+        assert_eq!(multi.as_slice(), [(2, 'c')]);
+    }
+
+    #[test]
+    fn leaked() {
+        let mut bst: BSTSet<usize> = set();
+        core::mem::forget(bst.drain_range(3..7).unwrap());
+        assert_eq!(bst.as_slice(), [0, 1, 2, 7, 8, 9]);
+        assert_eq!(bst.get(&7), Ok(&7));
+
+        core::mem::forget(bst.extract_if(| element | element % 2 == 0));
+        assert_eq!(bst.as_slice(), [1, 7, 9]);
+        assert_eq!(bst.insert(3), Ok(None));
+        assert_eq!(bst.as_slice(), [1, 3, 7, 9]);
+
+        core::mem::forget(bst.drain());
+        assert_eq!(bst.length(), 0);
+    }
+}
+
+mod queue {
+    use super::*;
+    use core::cmp::Ordering;
+    use arborist_core::Compare;
+
+    #[test]
+    fn pop_and_peek() {
+        use arborist::bst::bstset::*;
+
+        let mut bst: BSTSetConst<usize, 8> = BSTSetConst::new();
+        for element in [4, 1, 3, 2] {
+            bst.insert(element).unwrap();
+        }
+
+        assert_eq!((bst.peek_first(), bst.peek_last()), (Ok(&1), Ok(&4)));
+        assert_eq!(bst.pop_first(), Ok(1));
+        assert_eq!(bst.pop_last(), Ok(4));
+        assert_eq!(bst.as_slice(), [2, 3]);
+        assert_eq!(bst.get(&3), Ok(&3));
+
+        bst.pop_first().unwrap();
+        bst.pop_first().unwrap();
+        assert_eq!(bst.pop_first(), Err(BSTError::EMPTY));
+        assert_eq!(bst.pop_last(), Err(BSTError::EMPTY));
+        assert_eq!(bst.peek_first(), Err(BSTError::EMPTY));
+    }
+
+    #[test]
+    fn bounded() {
+        use arborist::bst::bstset::*;
+
+        let mut largest: BoundedBST<BSTSetConst<usize, 8>> = BoundedBST::new(3);
+        let mut smallest: BoundedBST<BSTSetConst<usize, 8>, Smallest> = BoundedBST::new(3);
+        for element in [5, 1, 9, 3, 7, 2, 8] {
+            largest.insert(element).unwrap();
+            smallest.insert(element).unwrap();
+        }
+        assert_eq!(largest.as_slice(), [7, 8, 9]);
+        assert_eq!(smallest.as_slice(), [1, 2, 3]);
+
+        assert_eq!(largest.insert(4), Ok(Some(4)));
+        assert_eq!(largest.insert(10), Ok(Some(7)));
+        assert_eq!(largest.insert(9), Ok(Some(9)));
+        assert_eq!(largest.pop_last(), Ok(10));
+        assert_eq!(largest.into_inner().as_slice(), [8, 9]);
+
+        // Bounds are clamped to the backing capacity
+        let clamped: BoundedBST<BSTSetConst<usize, 4>> = BoundedBST::new(usize::MAX);
+        assert_eq!(clamped.bound(), 3);
+        let mut empty: BoundedBST<BSTSet<usize>> = BoundedBST::new(0);
+        assert_eq!(empty.insert(1), Ok(Some(1)));
+        assert_eq!(empty.length(), 0);
+    }
+
+    #[test]
+    fn bounded_ties() {
+        use arborist::bst::bstmap::*;
+
+        let mut map: BoundedBST<BSTMap<usize, char>> = BoundedBST::new(2);
+        map.insert((1, 'a')).unwrap();
+        map.insert((2, 'b')).unwrap();
+        assert_eq!(map.insert((1, 'c')), Ok(Some((1, 'a'))));
+        assert_eq!(map.insert((2, 'd')), Ok(Some((2, 'b'))));
+        assert_eq!(map.get(&1), Ok(&'c'));
+
+        let mut multi: BoundedBST<BSTMultiMap<usize, char>, Smallest> = BoundedBST::new(2);
+        multi.insert((1, 'a')).unwrap();
+        multi.insert((2, 'b')).unwrap();
+        assert_eq!(multi.insert((2, 'c')), Ok(Some((2, 'c'))));
+        assert_eq!(multi.insert((1, 'd')), Ok(Some((2, 'b'))));
+        assert_eq!(multi.get_all(&1), Ok(&[(1, 'a'), (1, 'd')][..]));
+    }
+
+    // Orders NaN below 3.0 alone, so it passes the eviction check then fails to insert
+    struct NanBelowThree;
+
+    impl Compare<f64> for NanBelowThree {
+        fn compare(lhs: &f64, rhs: &f64) -> Option<Ordering> {
+            match (lhs.is_nan(), *rhs == 3.0) {
+                (true, true) => Some(Ordering::Less),
+                _ => lhs.partial_cmp(rhs)
+            }
+        }
+    }
+
+    #[test]
+    fn bounded_incomparable() {
+        use arborist::bst::bstset::*;
+
+        let mut bounded: BoundedBST<BSTSetConst<f64, 4>> = BoundedBST::new(3);
+        for element in [1.0, 2.0, 3.0] {
+            bounded.insert(element).unwrap();
+        }
+        assert_eq!(bounded.insert(f64::NAN), Err(BSTError::Incomparable));
+        assert_eq!(bounded.as_slice(), [1.0, 2.0, 3.0]);
+
+        let mut bounded: BoundedBST<BSTMultiSetConst<f64, 4, NanBelowThree>, Smallest> = BoundedBST::new(3);
+        for element in [1.0, 2.0, 3.0] {
+            bounded.insert(element).unwrap();
+        }
+        assert_eq!(bounded.insert(f64::NAN), Err(BSTError::Incomparable));
+        assert_eq!(bounded.length(), 3);
+        assert_eq!(bounded.as_slice(), [1.0, 2.0, 3.0]);
+    }
+}
+
+mod try_insert {
+    use super::*;
+
+    #[test]
+    fn spill() {
+        use arborist::bst::bstmap::*;
+
+        let mut bst: BSTMapConst<usize, String, 4> = BSTMapConst::new();
+        let mut spilled: Vec<(usize, String)> = Vec::new();
+        for key in 0..5 {
+            if let Err(err) = bst.try_insert((key, key.to_string())) {
+                assert_eq!(err.kind, BSTError::FULL);
+                spilled.push(err.into_value());
+            }
+        }
+
+        assert_eq!(bst.length(), 3);
+        assert_eq!(spilled, [(3, String::from("3")), (4, String::from("4"))]);
+        // Replacing never needs capacity
+        assert_eq!(bst.try_insert((1, String::from("one"))), Ok(Some((1, String::from("1")))));
+
+        match bst.entry(9).unwrap() {
+            Entry::Vacant(entry) => assert_eq!(
+                entry.try_insert(String::from("9")).map_err(| err | err.value),
+                Err((9, String::from("9")))
+            ),
+            Entry::Occupied(_) => unreachable!()
+        }
+    }
+
+    #[test]
+    fn entry_overflow() {
+        use arborist::bst::BST;
+        use arborist::bst::bstmap::*;
+        use arborist_core::EntryKey;
+
+        let mut bst: BST<Vec<(usize, char)>, u16, EntryKey> = BST::new();
+        for key in 0..u16::MAX as usize - 1 {
+            bst.insert(key, 'a').unwrap();
+        }
+
+        match bst.entry(usize::MAX).unwrap() {
+            Entry::Vacant(entry) => assert_eq!(
+                entry.try_insert('b').err(),
+                Some(InsertError::new(BSTError::Inner(FenwickTreeError::Overflow), (usize::MAX, 'b')))
+            ),
+            Entry::Occupied(_) => unreachable!()
+        }
+        assert_eq!(bst.length(), u16::MAX as usize - 1);
+    }
+
+    #[test]
+    fn rejected() {
+        use arborist::bst::bstset::*;
+
+        let mut bst: BSTSet<f64> = BSTSet::new();
+        assert_eq!((bst.insert(1.0), bst.insert(3.0)), (Ok(None), Ok(None)));
+        let err: InsertError<f64> = bst.try_insert(f64::NAN).err().unwrap();
+        assert_eq!(err.kind, BSTError::Incomparable);
+        assert!(err.value.is_nan());
+        assert_eq!(BSTError::from(err), BSTError::Incomparable);
+
+        let mut cursor = bst.cursor_front_mut();
+        assert_eq!(cursor.try_insert_after(5.0), Err(InsertError::new(BSTError::Unordered, 5.0)));
+        assert_eq!(cursor.try_insert_before(0.0), Ok(()));
+
+        let mut bounded: BoundedBST<BSTSet<f64>> = BoundedBST::new(1);
+        bounded.insert(1.0).unwrap();
+        assert_eq!(bounded.try_insert(f64::NAN).map_err(| err | err.kind), Err(BSTError::Incomparable));
+        assert_eq!(bounded.as_slice(), [1.0]);
+    }
+}
@@ -6,7 +6,7 @@ use criterion::{
 use rand::{SeedableRng, RngCore};
 use rand::rngs::{SmallRng, OsRng};
 
-use arborist_core::fenwick::compat::height;
+use arborist_core::fenwick::height;
 
 #[inline(always)]
 pub fn lsb(i: &usize) -> usize {
@@ -45,7 +45,7 @@ fn bench_fenwick<const N: usize>(b: &mut Criterion) {
             });
         });*/
 
-        group.bench_with_input("log2_bin_search", &next_val, | bencher, value | {
+        group.bench_with_input("log2_leading_zeros", &next_val, | bencher, value | {
             bencher.iter(|| {
                 black_box(height(*value))
             });
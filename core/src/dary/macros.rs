@@ -0,0 +1,215 @@
+macro_rules! safe_dary_index {
+    (@bounds($self:tt, $index:expr)) => {
+        require!(
+            $index > 0 && $index < $self.length(),
+            FenwickTreeError::OutOfBounds{index: $index, length: $self.length()}
+        );
+    };
+    // wrap_ret for the virtual walker
+    (virtual($self:tt, $index:expr $(, $mut:tt)?)) => {
+        safe_dary_index!(@bounds($self, $index));
+        return Ok($index);
+    };
+    // wrap_ret for stateful walkers
+    (stateful($self:tt, $index:expr $(, $mut:tt)?)) => {
+        safe_dary_index!(@bounds($self, $index));
+        return Ok(& $($mut)? $self.collection[$index]);
+    };
+}
+
+/*################################
+            Tree Walker
+################################*/
+
+macro_rules! impl_dary_walker {
+    (@up($self:ident)) => {
+        parent::<D>($self.curr.index)
+    };
+    (@down($self:ident, $ordinal:ident)) => {
+        match $ordinal < D {
+            true => first_child::<D>($self.curr.index).checked_add($ordinal).unwrap_or(0),
+            false => 0
+        }
+    };
+    // Horizontal steps stay within the current level
+    (@left($self:ident)) => {
+        match $self.curr.index > level_start::<D>($self.curr.depth) {
+            true => $self.curr.index - 1,
+            false => 0
+        }
+    };
+    (@right($self:ident)) => {
+        match $self.curr.index.saturating_add(1) < level_start::<D>($self.curr.depth + 1) {
+            true => $self.curr.index + 1,
+            false => 0
+        }
+    };
+    // No in-order exists past two children - step through storage (level) order instead
+    (@next($self:ident)) => {
+        $self.curr.index.checked_add(1).unwrap_or(0)
+    };
+    (@prev($self:ident)) => {
+        $self.curr.index.saturating_sub(1)
+    };
+    (@peek($fn:ident, $($mut:ident,)? $output:ty, $wrap_ret:ident)) => {
+        fn $fn(&'w $($mut)? self, direction: Direction<usize>) -> Result<$output, FenwickTreeError> {
+            let index: usize = match direction {
+                Direction::Up => impl_dary_walker!{@up(self)},
+                Direction::Down(ordinal) => impl_dary_walker!{@down(self, ordinal)},
+                Direction::Left => impl_dary_walker!{@left(self)},
+                Direction::Right => impl_dary_walker!{@right(self)},
+                Direction::Next => impl_dary_walker!{@next(self)},
+                Direction::Prev => impl_dary_walker!{@prev(self)}
+            };
+
+            safe_dary_index!{$wrap_ret(self, index $(, $mut)?)}
+        }
+    };
+    (@probe($fn:ident, $($mut:ident,)? $output:ty, $wrap_ret:ident)) => {
+        fn $fn(&'w $($mut)? self, path: impl Into<Self::Path>) -> Result<$output, FenwickTreeError> {
+            let index: usize = path.into();
+            safe_dary_index!{$wrap_ret(self, index $(, $mut)?)}
+        }
+    };
+    (@current($fn:ident, $($mut:ident,)? $output:ty, $wrap_ret:ident)) => {
+        fn $fn(&'w $($mut)? self) -> Result<$output, FenwickTreeError> {
+            let index: usize = self.curr.index;
+            safe_dary_index!{$wrap_ret(self, index $(, $mut)?)}
+        }
+    };
+    // Siblings cycle through the children of the shared parent
+    (@sibling($fn:ident, $($mut:ident,)? $output:ty, $wrap_ret:ident)) => {
+        fn $fn(&'w $($mut)? self) -> Result<$output, FenwickTreeError> {
+            let sibling: usize = match self.curr.index {
+                0 | 1 => 0,
+                index => first_child::<D>(parent::<D>(index)) + (ordinal::<D>(index) + 1) % D
+            };
+
+            safe_dary_index!{$wrap_ret(self, sibling $(, $mut)?)}
+        }
+    };
+    (@trait_body(output = $output:ty, return_wrapper = $wrap_ret:ident)) => {
+        type Path = usize;
+        type Side = usize;
+        type Output = $output;
+        type Error = FenwickTreeError;
+
+        impl_dary_walker!{@peek(peek, $output, $wrap_ret)}
+        impl_dary_walker!{@probe(probe, $output, $wrap_ret)}
+        impl_dary_walker!{@current(current, $output, $wrap_ret)}
+        impl_dary_walker!{@sibling(sibling, $output, $wrap_ret)}
+
+        fn traverse(&'w mut self, direction: Direction<usize>) {
+            let index: usize = match direction {
+                Direction::Up => impl_dary_walker!{@up(self)},
+                Direction::Down(ordinal) => impl_dary_walker!{@down(self, ordinal)},
+                Direction::Left => impl_dary_walker!{@left(self)},
+                Direction::Right => impl_dary_walker!{@right(self)},
+                Direction::Next => impl_dary_walker!{@next(self)},
+                Direction::Prev => impl_dary_walker!{@prev(self)}
+            };
+
+            self.curr.update(index);
+        }
+
+        fn seek(&'w mut self, path: impl Into<Self::Path>) {
+            self.curr.update(path.into());
+        }
+
+        fn reset(&mut self) {
+            self.curr.update(1);
+        }
+
+        fn node_type(&self) -> NodeType {
+            match first_child::<D>(self.curr.index) {
+                0 => NodeType::Leaf,
+                child if child >= self.length() => NodeType::Leaf,
+                _ => NodeType::Node
+            }
+        }
+
+        fn node_side(&self) -> usize {
+            self.curr.ordinal()
+        }
+    };
+    (@aux_methods_body) => {
+        pub fn index(&self) -> usize {
+            self.curr.index
+        }
+
+        pub fn depth(&self) -> usize {
+            self.curr.depth
+        }
+
+        pub fn height(&self) -> usize {
+            height::<D>(self.length())
+        }
+
+        pub fn children(&self) -> Range<usize> {
+            let first: usize = first_child::<D>(self.curr.index);
+            match first {
+                0 => 0..0,
+                _ => first.min(self.length())..first.saturating_add(D).min(self.length())
+            }
+        }
+    };
+    (aux_methods(type = DaryTreeView)) => {
+        impl<const D: usize> DaryTreeView<D> {
+            pub fn new(collection: &impl Length, index: usize) -> Result<Self, FenwickTreeError> {
+                require!(index > 0, FenwickTreeError::OutOfBounds{ index: 0, length: collection.length() });
+
+                Ok(Self {
+                    length: collection.length(),
+                    curr: DaryIndexView::new(index)
+                })
+            }
+
+            impl_dary_walker!{@aux_methods_body}
+        }
+    };
+    (aux_methods(type = $target_type:ident $(: $mut:tt)?)) => {
+        impl<'a, C, const D: usize> $target_type<'a, C, D> where
+            C: ?Sized + IndexedCollection,
+            C::Output: Sized
+        {
+            pub fn new(collection: &'a $($mut)? C, index: usize) -> Result<Self, FenwickTreeError> {
+                require!(index > 0, FenwickTreeError::OutOfBounds{ index: 0, length: collection.length() });
+
+                Ok(Self {
+                    collection,
+                    curr: DaryIndexView::new(index)
+                })
+            }
+
+            impl_dary_walker!{@aux_methods_body}
+        }
+    };
+    (trait(type = DaryTreeView, output = $output:ty, return_wrapper = $wrap_ret:ident)) => {
+        impl<'w, const D: usize> TreeWalker<'w> for DaryTreeView<D> {
+            impl_dary_walker!{@trait_body(output = $output, return_wrapper = $wrap_ret)}
+        }
+    };
+    (trait(type = $target_type:ident, output = $output:ty, return_wrapper = $wrap_ret:ident)) => {
+        impl<'t, 'w, C, const D: usize> TreeWalker<'w> for $target_type<'t, C, D> where
+            C: ?Sized + IndexedCollection,
+            C::Output: Sized,
+            't: 'w
+        {
+            impl_dary_walker!{@trait_body(output = $output, return_wrapper = $wrap_ret)}
+        }
+    };
+    (trait_mut(type = $target_type:ident, output = $output:ty, return_wrapper = $wrap_ret:ident)) => {
+        impl<'t, 'w, C, const D: usize> TreeWalkerMut<'w> for $target_type<'t, C, D> where
+            C: ?Sized + IndexedCollectionMut,
+            C::Output: Sized,
+            't: 'w
+        {
+            type OutputMut = $output;
+
+            impl_dary_walker!{@peek(peek_mut, mut, $output, $wrap_ret)}
+            impl_dary_walker!{@probe(probe_mut, mut, $output, $wrap_ret)}
+            impl_dary_walker!{@current(current_mut, mut, $output, $wrap_ret)}
+            impl_dary_walker!{@sibling(sibling_mut, mut, $output, $wrap_ret)}
+        }
+    };
+}
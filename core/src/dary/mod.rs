@@ -0,0 +1,159 @@
+#[macro_use]
+mod macros;
+
+#[cfg(test)]
+mod tests;
+
+use core::ops::Range;
+use crate::{
+    NodeType, Direction,
+    TreeWalker, TreeWalkerMut,
+    require
+};
+use crate::fenwick::{
+    FenwickTreeError, IndexedCollection,
+    IndexedCollectionMut, Length
+};
+use arborist_proc::{
+    Length, length_method
+};
+
+/*################################
+            Functions
+################################*/
+
+// Nodes are laid out level by level from index 1, leaving index 0 free as
+// with the Fenwick layout. Index 0 doubles as the 'no such node' result.
+
+#[inline(always)]
+pub fn first_child<const D: usize>(index: usize) -> usize {
+    match index {
+        0 => 0,
+        _ => (index - 1).checked_mul(D).and_then(| offset | offset.checked_add(2)).unwrap_or(0)
+    }
+}
+
+#[inline(always)]
+pub fn parent<const D: usize>(index: usize) -> usize {
+    match index {
+        0 | 1 => 0,
+        _ => (index - 2) / D + 1
+    }
+}
+
+#[inline(always)]
+pub fn ordinal<const D: usize>(index: usize) -> usize {
+    match index {
+        0 | 1 => 0,
+        _ => (index - 2) % D
+    }
+}
+
+pub fn level_start<const D: usize>(depth: usize) -> usize {
+    let (mut start, mut width): (usize, usize) = (1, 1);
+    for _ in 0..depth {
+        start = start.saturating_add(width);
+        width = width.saturating_mul(D);
+    }
+
+    start
+}
+
+pub fn depth<const D: usize>(index: usize) -> usize {
+    let (mut depth, mut end, mut width): (usize, usize, usize) = (0, 2, D);
+    while index >= end && end != usize::MAX {
+        end = end.saturating_add(width);
+        width = width.saturating_mul(D);
+        depth += 1;
+    }
+
+    depth
+}
+
+// Depth of the deepest node held by a collection of `length`
+pub fn height<const D: usize>(length: usize) -> usize {
+    depth::<D>(length.saturating_sub(1))
+}
+
+/*################################
+            Index View
+################################*/
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct DaryIndexView<const D: usize> {
+    pub(crate) index: usize,
+    pub(crate) depth: usize
+}
+
+impl<const D: usize> DaryIndexView<D> {
+    fn new(index: usize) -> Self {
+        const { assert!(D >= 2, "D-ary trees require an arity of at least 2") };
+
+        Self {
+            index,
+            depth: depth::<D>(index)
+        }
+    }
+
+    fn update(&mut self, new: usize) -> &mut Self {
+        self.index = new;
+        self.depth = depth::<D>(new);
+
+        self
+    }
+
+    pub fn ordinal(&self) -> usize {
+        ordinal::<D>(self.index)
+    }
+
+    pub fn level_range(&self) -> Range<usize> {
+        level_start::<D>(self.depth)..level_start::<D>(self.depth + 1)
+    }
+}
+
+impl<const D: usize> PartialEq<usize> for DaryIndexView<D> {
+    fn eq(&self, other: &usize) -> bool {
+        &self.index == other
+    }
+}
+
+/*################################
+           Tree Walkers
+################################*/
+
+#[derive(Debug, Clone, PartialEq, Length)]
+#[length_method(self.length)]
+pub struct DaryTreeView<const D: usize> {
+    length: usize,
+    pub curr: DaryIndexView<D>
+}
+
+#[derive(Debug, Clone, PartialEq, Length)]
+#[length_method(self.collection.length())]
+pub struct DaryStatefulTreeView<'a, C: ?Sized + Length, const D: usize> {
+    collection: &'a C,
+    pub curr: DaryIndexView<D>
+}
+
+#[derive(Debug, PartialEq, Length)]
+#[length_method(self.collection.length())]
+pub struct DaryStatefulTreeViewMut<'a, C: ?Sized + Length, const D: usize> {
+    collection: &'a mut C,
+    pub curr: DaryIndexView<D>
+}
+
+pub type QuaternaryTreeView = DaryTreeView<4>;
+pub type OctonaryTreeView = DaryTreeView<8>;
+
+/*################################
+           Walker Impls
+################################*/
+
+impl_dary_walker!{aux_methods(type = DaryTreeView)}
+impl_dary_walker!{aux_methods(type = DaryStatefulTreeView)}
+impl_dary_walker!{aux_methods(type = DaryStatefulTreeViewMut: mut)}
+
+impl_dary_walker!{trait(type = DaryTreeView, output = usize, return_wrapper = virtual)}
+impl_dary_walker!{trait(type = DaryStatefulTreeView, output = &'w C::Output, return_wrapper = stateful)}
+impl_dary_walker!{trait(type = DaryStatefulTreeViewMut, output = &'w C::Output, return_wrapper = stateful)}
+impl_dary_walker!{trait_mut(type = DaryStatefulTreeViewMut, output = &'w mut C::Output, return_wrapper = stateful)}
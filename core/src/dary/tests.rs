@@ -0,0 +1,189 @@
+use sith::test_suite;
+use arborist_proc::impl_mock;
+
+use crate::*;
+use crate::dary::*;
+use crate::fenwick::FenwickTreeError;
+
+impl_mock!(MockCollection);
+
+macro_rules! esc {
+    ($($tokens:tt)*) => {
+        $($tokens)*
+    };
+}
+
+macro_rules! generate_walker {
+    ($walker:ty, $inner:ident, $index:expr) => {
+        {
+            let expected = $inner.clone();
+            (<$walker>::new(&mut $inner, $index).unwrap(), expected)
+        }
+    };
+}
+
+// Ternary layout used throughout, LENGTH = 14:
+//
+//                       1
+//          2            3            4
+//      5   6   7    8   9   10   11  12  13
+
+#[test_suite]
+mod auxiliary {
+    use super::*;
+    use sith::test_case;
+
+    #[test_case]
+    fn first_child_and_parent() {
+        assert_eq!(first_child::<3>(0), 0);
+        assert_eq!(first_child::<3>(1), 2);
+        assert_eq!(first_child::<3>(3), 8);
+        assert_eq!(first_child::<3>(usize::MAX), 0);
+
+        assert_eq!(parent::<3>(1), 0);
+        assert_eq!(parent::<3>(4), 1);
+        assert_eq!(parent::<3>(8), 3);
+        assert_eq!(parent::<3>(13), 4);
+    }
+
+    #[test_case]
+    fn ordinals() {
+        assert_eq!(ordinal::<3>(1), 0);
+        assert_eq!(ordinal::<3>(5), 0);
+        assert_eq!(ordinal::<3>(9), 1);
+        assert_eq!(ordinal::<3>(13), 2);
+    }
+
+    #[test_case(binary, with(2, 5, 2))]
+    #[test_case(ternary, with(3, 5, 2))]
+    #[test_case(quaternary, with(4, 22, 3))]
+    fn depths(r#arity: _, index: usize, expected: usize) {
+        assert_eq!(depth::<r#arity>(index), expected);
+        assert_eq!(depth::<r#arity>(level_start::<r#arity>(expected)), expected);
+    }
+
+    #[test_case]
+    fn level_starts() {
+        assert_eq!(level_start::<3>(0), 1);
+        assert_eq!(level_start::<3>(1), 2);
+        assert_eq!(level_start::<3>(2), 5);
+        assert_eq!(level_start::<3>(3), 14);
+        assert_eq!(level_start::<3>(usize::BITS as usize), usize::MAX);
+    }
+
+    #[test_case]
+    fn heights() {
+        assert_eq!(height::<3>(1), 0);
+        assert_eq!(height::<3>(2), 0);
+        assert_eq!(height::<3>(5), 1);
+        assert_eq!(height::<3>(14), 2);
+        assert_eq!(height::<3>(15), 3);
+    }
+
+    #[test_case]
+    fn indexview_level_range() {
+        let view: DaryIndexView<3> = DaryIndexView::new(9);
+        assert_eq!(view.depth, 2);
+        assert_eq!(view.ordinal(), 1);
+        assert_eq!(view.level_range(), 5..14);
+    }
+}
+
+#[test_suite]
+mod walkers {
+    use super::*;
+    use sith::test_case;
+
+    #[test_case(virtual_view, with(verbatim(DaryTreeView<3>), verbatim()))]
+    #[test_case(stateful_view, with(verbatim(DaryStatefulTreeView<MockCollection, 3>), verbatim()))]
+    #[test_case(stateful_view_mut, with(verbatim(DaryStatefulTreeViewMut<MockCollection, 3>), verbatim(mut)))]
+    fn new_errors_on_zero_index(r#walker: _, r#mut: _) {
+        assert_eq!(
+            esc!(<r#walker>::new(&r#mut MockCollection::new(14), 0)),
+            Err(FenwickTreeError::OutOfBounds { index: 0, length: 14 })
+        );
+    }
+
+    #[test_case(virtual_view, with(verbatim(DaryTreeView<3>), verbatim(peek), verbatim()))]
+    #[test_case(stateful_view, with(verbatim(DaryStatefulTreeView<MockCollection, 3>), verbatim(peek), verbatim(&)))]
+    #[test_case(stateful_view_mut, with(verbatim(DaryStatefulTreeViewMut<MockCollection, 3>), verbatim(peek), verbatim(&)))]
+    #[test_case(mut_stateful_view_mut, with(verbatim(DaryStatefulTreeViewMut<MockCollection, 3>), verbatim(peek_mut), verbatim(&mut)))]
+    fn peek(r#walker_ty: _, r#peek_fn: _, r#output_ref: _) {
+        let mut inner: MockCollection = MockCollection::new(14);
+        let (mut walker, mut expected) = generate_walker!(r#walker_ty, inner, 3);
+
+        assert_eq!(walker.r#peek_fn(Direction::Up), Ok(r#output_ref expected[1]));
+        assert_eq!(walker.r#peek_fn(Direction::Down(0)), Ok(r#output_ref expected[8]));
+        assert_eq!(walker.r#peek_fn(Direction::Down(2)), Ok(r#output_ref expected[10]));
+        assert_eq!(walker.r#peek_fn(Direction::Down(3)), Err(FenwickTreeError::OutOfBounds { index: 0, length: 14 }));
+        assert_eq!(walker.r#peek_fn(Direction::Left), Ok(r#output_ref expected[2]));
+        assert_eq!(walker.r#peek_fn(Direction::Right), Ok(r#output_ref expected[4]));
+    }
+
+    #[test_case(virtual_view, with(verbatim(DaryTreeView<3>)))]
+    #[test_case(stateful_view, with(verbatim(DaryStatefulTreeView<MockCollection, 3>)))]
+    #[test_case(stateful_view_mut, with(verbatim(DaryStatefulTreeViewMut<MockCollection, 3>)))]
+    fn horizontal_steps_stay_on_level(r#walker_ty: _) {
+        let mut inner: MockCollection = MockCollection::new(14);
+        let (mut walker, _) = generate_walker!(r#walker_ty, inner, 4);
+
+        assert_eq!(walker.peek(Direction::Right), Err(FenwickTreeError::OutOfBounds { index: 0, length: 14 }));
+
+        walker.seek(5usize);
+        assert_eq!(walker.peek(Direction::Left), Err(FenwickTreeError::OutOfBounds { index: 0, length: 14 }));
+
+        walker.seek(1usize);
+        assert_eq!(walker.peek(Direction::Left), Err(FenwickTreeError::OutOfBounds { index: 0, length: 14 }));
+        assert_eq!(walker.peek(Direction::Right), Err(FenwickTreeError::OutOfBounds { index: 0, length: 14 }));
+    }
+
+    #[test_case(virtual_view, with(verbatim(DaryTreeView<3>)))]
+    #[test_case(stateful_view, with(verbatim(DaryStatefulTreeView<MockCollection, 3>)))]
+    #[test_case(stateful_view_mut, with(verbatim(DaryStatefulTreeViewMut<MockCollection, 3>)))]
+    fn traverse(r#walker_ty: _) {
+        let mut inner: MockCollection = MockCollection::new(14);
+        let (mut walker, _) = generate_walker!(r#walker_ty, inner, 1);
+
+        walker.traverse(Direction::Down(1));
+        assert_eq!(walker.index(), 3);
+        assert_eq!(walker.depth(), 1);
+        assert_eq!(walker.node_side(), 1);
+
+        walker.traverse(Direction::Down(2));
+        assert_eq!(walker.index(), 10);
+        assert_eq!(walker.node_type(), NodeType::Leaf);
+
+        walker.traverse(Direction::Right);
+        walker.traverse(Direction::Up);
+        assert_eq!(walker.index(), 4);
+        assert_eq!(walker.node_type(), NodeType::Node);
+
+        // Wider trees have no in-order, so Next/Prev follow storage order
+        walker.traverse(Direction::Next);
+        assert_eq!(walker.index(), 5);
+        walker.traverse(Direction::Prev);
+        assert_eq!(walker.index(), 4);
+
+        walker.reset();
+        assert_eq!(walker.index(), 1);
+    }
+
+    #[test_case(virtual_view, with(verbatim(DaryTreeView<3>)))]
+    #[test_case(stateful_view, with(verbatim(DaryStatefulTreeView<MockCollection, 3>)))]
+    #[test_case(stateful_view_mut, with(verbatim(DaryStatefulTreeViewMut<MockCollection, 3>)))]
+    fn siblings_and_children(r#walker_ty: _) {
+        let mut inner: MockCollection = MockCollection::new(12);
+        let (mut walker, _) = generate_walker!(r#walker_ty, inner, 1);
+
+        assert_eq!(walker.sibling(), Err(FenwickTreeError::OutOfBounds { index: 0, length: 12 }));
+        assert_eq!(walker.children(), 2..5);
+
+        walker.seek(4usize);
+        assert_eq!(walker.sibling(), walker.probe(2usize));
+        // Only the first child of 4 exists in a collection of 12
+        assert_eq!(walker.children(), 11..12);
+
+        walker.seek(11usize);
+        assert_eq!(walker.sibling(), Err(FenwickTreeError::OutOfBounds { index: 12, length: 12 }));
+    }
+}
@@ -1,9 +1,9 @@
 macro_rules! impl_op {
-    ($trait:ty, $fn:ident, $op:tt, $rhs:ty) => {
-        impl $trait for IndexView {
-            type Output = $rhs;
+    ($trait:ident, $fn:ident, $op:tt) => {
+        impl<I: Index> $trait<I> for IndexView<I> {
+            type Output = I;
 
-            fn $fn(self, rhs: $rhs) -> Self::Output {
+            fn $fn(self, rhs: I) -> Self::Output {
                 self.index $op rhs
             }
         }
@@ -11,11 +11,10 @@ macro_rules! impl_op {
 }
 
 macro_rules! impl_op_assign {
-    ($trait:ty, $fn:ident, $op:tt, $rhs:ty) => {
-        impl $trait for IndexView {
-            fn $fn(&mut self, rhs: $rhs) {
-                self.index $op rhs;
-                self.lsb = lsb(self.index);
+    ($trait:ident, $fn:ident, $op:tt) => {
+        impl<I: Index> $trait<I> for IndexView<I> {
+            fn $fn(&mut self, rhs: I) {
+                self.update(self.index $op rhs);
             }
         }
     };
@@ -24,7 +23,7 @@ macro_rules! impl_op_assign {
 macro_rules! safe_tree_index {
     (@bounds($self:tt, $index:expr)) => {
         require!(
-            $index > 0 && $index < $self.length(),
+            $index != Default::default() && $index.as_usize() < $self.length(),
             FenwickTreeError::OutOfBounds{index: $index, length: $self.length()}
         );
     };
@@ -36,7 +35,7 @@ macro_rules! safe_tree_index {
     // wrap_ret for StatefulTreeWalkers
     (stateful($self:tt, $index:expr $(, $mut:tt)?)) => {
         safe_tree_index!(@bounds($self, $index));
-        return Ok(& $($mut)? $self.collection[$index]);
+        return Ok(& $($mut)? $self.collection[$index.as_usize()]);
     };
 }
 
@@ -45,95 +44,57 @@ macro_rules! safe_tree_index {
 ################################*/
 
 macro_rules! impl_walker {
-    (@up($self:ident)) => {
-        ($self.curr | $self.curr.lsb << 1) ^ $self.curr.lsb
-    };
-    (@down($self:ident, $side:ident)) => {
-        // Transition downward to next 'lsb namespace'
-        match $side {
-            NodeSide::Left => ($self.curr | $self.curr.lsb >> 1) ^ $self.curr.lsb,
-            NodeSide::Right => ($self.curr | $self.curr.lsb) ^ $self.curr.lsb >> 1
-        }
-    };
-    (@left($self:ident, $op:tt)) => {
-        $self.curr.index $op ($self.curr.lsb << 1).min($self.curr.index)
-    };
-    (@right($self:ident, $op:tt)) => {
-        $self.curr $op ($self.curr.lsb << 1)
-    };
-    (@peek($fn:ident, $($mut:ident,)? $output:ty, $($wrap_ret:tt)+)) => {
-        fn $fn(&'w $($mut)? self, direction: Direction) -> Result<$output, FenwickTreeError> {
-            let index: usize = match direction {
-                Direction::Up => {
-                    impl_walker!{@up(self)}
-                },
-                Direction::Down(side) => {
-                    impl_walker!{@down(self, side)}
-                },
-                Direction::Left => {
-                    impl_walker!{@left(self, -)}
-                },
-                Direction::Right => {
-                    impl_walker!{@right(self, +)}
-                }
-            };
-
+    (@peek($fn:ident, $($mut:ident,)? $index:ty, $output:ty, $($wrap_ret:tt)+)) => {
+        fn $fn(&'w $($mut)? self, direction: Direction) -> Result<$output, FenwickTreeError<$index>> {
+            let index: $index = self.curr.step(direction).ok_or(FenwickTreeError::Overflow)?;
             interpolate!{ret => {index}, $($wrap_ret)+}
         }
     };
-    (@probe($fn:ident, $($mut:ident,)? $output:ty, $($wrap_ret:tt)+)) => {
-        fn $fn(&'w $($mut)? self, path: Self::Path) -> Result<$output, FenwickTreeError> {
-            interpolate!(ret => {path}, $($wrap_ret)+);
+    (@probe($fn:ident, $($mut:ident,)? $index:ty, $output:ty, $($wrap_ret:tt)+)) => {
+        fn $fn(&'w $($mut)? self, path: impl Into<Self::Path>) -> Result<$output, FenwickTreeError<$index>> {
+            let index: $index = path.into().resolve(self.height()).ok_or(FenwickTreeError::Overflow)?;
+            interpolate!(ret => {index}, $($wrap_ret)+);
         }
     };
-    (@traverse($fn:ident, $output:ty, $($wrap_ret:tt)+)) => {
+    // Steps past the index width land on index 0, which is never in bounds
+    (@traverse($fn:ident, $index:ty)) => {
         fn $fn(&'w mut self, direction: Direction) {
-            match direction {
-                Direction::Up => {
-                    self.curr.update(impl_walker!{@up(self)});
-                },
-                Direction::Down(side) => {
-                    self.curr.update(impl_walker!{@down(self, side)});
-                },
-                Direction::Left => {
-                    impl_walker!(@left(self, -=));
-                },
-                Direction::Right => {
-                    impl_walker!(@right(self, +=));
-                }
-            };
+            let index: $index = self.curr.step(direction).unwrap_or_default();
+            self.curr.update(index);
         }
     };
-    (@seek($fn:ident, $output:ty, $($wrap_ret:tt)+)) => {
-        fn $fn(&'w mut self, path: Self::Path) {
-            self.curr.update(path);
+    (@seek($fn:ident, $index:ty)) => {
+        fn $fn(&'w mut self, path: impl Into<Self::Path>) {
+            let index: $index = path.into().resolve(self.height()).unwrap_or_default();
+            self.curr.update(index);
         }
     };
-    (@current($fn:ident, $($mut:ident,)? $output:ty, $($wrap_ret:tt)+)) => {
-        fn $fn(&'w $($mut)? self) -> Result<$output, FenwickTreeError> {
+    (@current($fn:ident, $($mut:ident,)? $index:ty, $output:ty, $($wrap_ret:tt)+)) => {
+        fn $fn(&'w $($mut)? self) -> Result<$output, FenwickTreeError<$index>> {
             interpolate!(ret => {self.curr.index}, $($wrap_ret)+);
         }
     };
-    (@sibling($fn:ident, $($mut:ident,)? $output:ty, $($wrap_ret:tt)+)) => {
-        fn $fn(&'w $($mut)? self) -> Result<$output, FenwickTreeError> {
-            let sibling: usize = self.curr.index ^ self.curr.lsb << 1;
+    (@sibling($fn:ident, $($mut:ident,)? $index:ty, $output:ty, $($wrap_ret:tt)+)) => {
+        fn $fn(&'w $($mut)? self) -> Result<$output, FenwickTreeError<$index>> {
+            let sibling: $index = self.curr.sibling();
             interpolate!(ret => {sibling}, $($wrap_ret)+);
         }
     };
-    (@trait_body(output = $output:ty, return_wrapper = $($wrap_ret:tt)+)) => {
-        type Path = usize;
+    (@trait_body(index = $index:ty, output = $output:ty, return_wrapper = $($wrap_ret:tt)+)) => {
+        type Path = WalkerPath<$index>;
+        type Side = NodeSide;
         type Output = $output;
-        type Error = FenwickTreeError;
+        type Error = FenwickTreeError<$index>;
 
-        impl_walker!{@peek(peek, $output, $($wrap_ret)+)}
-        impl_walker!{@probe(probe, $output, $($wrap_ret)+)}
-        impl_walker!{@traverse(traverse, $output, $($wrap_ret)+)}
-        impl_walker!{@seek(seek, $output, $($wrap_ret)+)}
-        impl_walker!{@current(current, $output, $($wrap_ret)+)}
-        impl_walker!{@sibling(sibling, $output, $($wrap_ret)+)}
+        impl_walker!{@peek(peek, $index, $output, $($wrap_ret)+)}
+        impl_walker!{@probe(probe, $index, $output, $($wrap_ret)+)}
+        impl_walker!{@traverse(traverse, $index)}
+        impl_walker!{@seek(seek, $index)}
+        impl_walker!{@current(current, $index, $output, $($wrap_ret)+)}
+        impl_walker!{@sibling(sibling, $index, $output, $($wrap_ret)+)}
 
         fn reset(&mut self) {
-            self.curr.index = self.length();
+            self.curr.index = <$index>::from_usize(self.length()).unwrap_or_default();
         }
 
         fn node_type(&self) -> NodeType {
@@ -145,18 +106,69 @@ macro_rules! impl_walker {
         }
     };
     (@aux_methods_body) => {
-        pub fn index(&self) -> usize {
+        pub fn index(&self) -> I {
             self.curr.index
         }
 
-        pub fn lsb(&self) -> usize {
+        pub fn lsb(&self) -> I {
             self.curr.lsb
         }
+
+        pub fn depth(&self) -> Result<usize, FenwickTreeError<I>> {
+            self.level_of(self.curr.index)
+        }
+
+        pub fn level_of(&self, index: I) -> Result<usize, FenwickTreeError<I>> {
+            safe_tree_index!(@bounds(self, index));
+            Ok(self.height() - IndexView::new(index).level())
+        }
+
+        // Steps from the root down to the current node
+        pub fn path(&self) -> Result<TreePath, FenwickTreeError<I>> {
+            let (index, height): (usize, usize) = (self.curr.index.as_usize(), self.height());
+            let mut path: TreePath = TreePath::root();
+
+            for step in 0..self.depth()? {
+                let side: NodeSide = match index >> (height - step) & 1 {
+                    0 => NodeSide::Left,
+                    _ => NodeSide::Right
+                };
+                path.try_push(side).map_err(| _ | FenwickTreeError::Overflow)?;
+            }
+
+            Ok(path)
+        }
+
+        pub fn kth_ancestor(&self, k: usize) -> Result<I, FenwickTreeError<I>> {
+            let ancestor: I = self.curr.ancestor(k);
+            safe_tree_index!(@bounds(self, ancestor));
+            Ok(ancestor)
+        }
+
+        pub fn lowest_common_ancestor(&self, a: I, b: I) -> Result<I, FenwickTreeError<I>> {
+            safe_tree_index!(@bounds(self, a));
+            safe_tree_index!(@bounds(self, b));
+            Ok(IndexView::new(a).common_ancestor(&IndexView::new(b)).index)
+        }
+
+        pub fn distance(&self, a: I, b: I) -> Result<usize, FenwickTreeError<I>> {
+            let common: usize = IndexView::new(self.lowest_common_ancestor(a, b)?).level();
+            Ok((common - IndexView::new(a).level()) + (common - IndexView::new(b).level()))
+        }
+
+        pub fn is_ancestor_of(&self, a: I, b: I) -> bool {
+            IndexView::new(a).is_ancestor_of(&IndexView::new(b))
+        }
+
+        // Spans the full implicit subtree - may extend past the collection
+        pub fn subtree_range(&self) -> RangeInclusive<I> {
+            self.curr.subtree_range()
+        }
     };
     (aux_methods(type = VirtualTreeView)) => {
-        impl VirtualTreeView {
-            pub fn new(collection: &impl Length, index: usize) -> Result<Self, FenwickTreeError> {
-                require!(index > 0, FenwickTreeError::OutOfBounds { index: 0, length: collection.length() });
+        impl<I: Index> VirtualTreeView<I> {
+            pub fn new(collection: &impl Length, index: I) -> Result<Self, FenwickTreeError<I>> {
+                require!(index != I::ZERO, FenwickTreeError::OutOfBounds { index: I::ZERO, length: collection.length() });
 
                 Ok(Self {
                     length: collection.length(),
@@ -167,13 +179,62 @@ macro_rules! impl_walker {
             impl_walker!{@aux_methods_body}
         }
     };
+    (aux_methods(type = ConstTreeView)) => {
+        impl<const LEN: usize> ConstTreeView<LEN> {
+            pub const HEIGHT: usize = height(LEN);
+            pub const ROOT: usize = root(&Self::HEIGHT);
+
+            pub const fn new(index: usize) -> Result<Self, FenwickTreeError> {
+                require!(index > 0, FenwickTreeError::OutOfBounds{ index: 0, length: LEN });
+
+                Ok(Self {
+                    curr: IndexView::<usize>::new_const(index)
+                })
+            }
+
+            pub const fn at_root() -> Self {
+                Self {
+                    curr: IndexView::<usize>::new_const(Self::ROOT)
+                }
+            }
+
+            pub const fn index(&self) -> usize {
+                self.curr.index
+            }
+
+            // Const counterparts of the TreeWalker methods
+            pub const fn peek_const(&self, direction: Direction) -> Result<usize, FenwickTreeError> {
+                Self::bounded(step(self.curr.index, direction))
+            }
+
+            pub const fn probe_const(&self, index: usize) -> Result<usize, FenwickTreeError> {
+                Self::bounded(index)
+            }
+
+            pub const fn sibling_const(&self) -> Result<usize, FenwickTreeError> {
+                Self::bounded(sibling(self.curr.index))
+            }
+
+            pub const fn stepped(self, direction: Direction) -> Self {
+                Self {
+                    curr: IndexView::<usize>::new_const(step(self.curr.index, direction))
+                }
+            }
+
+            pub const fn bounded(index: usize) -> Result<usize, FenwickTreeError> {
+                require!(index > 0 && index < LEN, FenwickTreeError::OutOfBounds{ index, length: LEN });
+                Ok(index)
+            }
+        }
+    };
     (aux_methods(type = $target_type:ident $(: $mut:tt)?)) => {
-        impl<'a, C> $target_type<'a, C> where
+        impl<'a, C, I> $target_type<'a, C, I> where
             C: ?Sized + IndexedCollection,
-            C::Output: Sized
+            C::Output: Sized,
+            I: Index
         {
-            pub fn new(collection: &'a $($mut)? C, index: usize) -> Result<Self, FenwickTreeError> {
-                require!(index > 0, FenwickTreeError::OutOfBounds{ index: 0, length: collection.length() });
+            pub fn new(collection: &'a $($mut)? C, index: I) -> Result<Self, FenwickTreeError<I>> {
+                require!(index != I::ZERO, FenwickTreeError::OutOfBounds{ index: I::ZERO, length: collection.length() });
         
                 Ok(Self {
                     collection,
@@ -185,31 +246,38 @@ macro_rules! impl_walker {
         }
     };
     (trait(type = VirtualTreeView, output = $output:ty, return_wrapper = $($wrap_ret:tt)+)) => {
-        impl<'w> TreeWalker<'w> for VirtualTreeView {
-            impl_walker!{@trait_body(output = $output, return_wrapper = $($wrap_ret)+)}
+        impl<'w, I: Index> TreeWalker<'w> for VirtualTreeView<I> {
+            impl_walker!{@trait_body(index = I, output = $output, return_wrapper = $($wrap_ret)+)}
+        }
+    };
+    (trait(type = ConstTreeView, output = $output:ty, return_wrapper = $($wrap_ret:tt)+)) => {
+        impl<'w, const LEN: usize> TreeWalker<'w> for ConstTreeView<LEN> {
+            impl_walker!{@trait_body(index = usize, output = $output, return_wrapper = $($wrap_ret)+)}
         }
     };
     (trait(type = $target_type:ident, output = $output:ty, return_wrapper = $($wrap_ret:tt)+)) => {
-        impl<'t, 'w, C> TreeWalker<'w> for $target_type<'t, C> where
+        impl<'t, 'w, C, I> TreeWalker<'w> for $target_type<'t, C, I> where
             C: ?Sized + IndexedCollection,
             C::Output: Sized,
+            I: Index,
             't: 'w
         {
-            impl_walker!{@trait_body(output = $output, return_wrapper = $($wrap_ret)+)}
+            impl_walker!{@trait_body(index = I, output = $output, return_wrapper = $($wrap_ret)+)}
         }
     };
     (trait_mut(type = $target_type:ident, output = $output:ty, return_wrapper = $($wrap_ret:tt)+)) => {
-        impl<'t, 'w, C> TreeWalkerMut<'w> for $target_type<'t, C> where
+        impl<'t, 'w, C, I> TreeWalkerMut<'w> for $target_type<'t, C, I> where
             C: ?Sized + IndexedCollectionMut,
             C::Output: Sized,
+            I: Index,
             't: 'w
         {
             type OutputMut = $output;
 
-            impl_walker!{@peek(peek_mut, mut, $output, $($wrap_ret)+)}
-            impl_walker!{@probe(probe_mut, mut, $output, $($wrap_ret)+)}
-            impl_walker!{@current(current_mut, mut, $output, $($wrap_ret)+)}
-            impl_walker!{@sibling(sibling_mut, mut, $output, $($wrap_ret)+)}
+            impl_walker!{@peek(peek_mut, mut, I, $output, $($wrap_ret)+)}
+            impl_walker!{@probe(probe_mut, mut, I, $output, $($wrap_ret)+)}
+            impl_walker!{@current(current_mut, mut, I, $output, $($wrap_ret)+)}
+            impl_walker!{@sibling(sibling_mut, mut, I, $output, $($wrap_ret)+)}
         }
     };
-}
\ No newline at end of file
+}
@@ -1,4 +1,5 @@
 pub mod traits;
+pub mod traversal;
 
 #[macro_use]
 mod macros;
@@ -11,11 +12,11 @@ use core::ops::{
     BitXor, BitXorAssign,
     BitAnd, BitAndAssign,
     AddAssign, SubAssign,
-    Add, Sub
+    Add, Sub, RangeInclusive
 };
 use crate::{
     NodeSide, NodeType, Direction,
-    TreeWalker, TreeWalkerMut,
+    TreeWalker, TreeWalkerMut, TreePath,
     require
 };
 use arborist_proc::{
@@ -23,20 +24,111 @@ use arborist_proc::{
 };
 
 pub use traits::*;
+pub use traversal::*;
 
 /*################################
             Functions
 ################################*/
 
 #[inline(always)]
-pub fn lsb(i: usize) -> usize {
+pub const fn lsb(i: usize) -> usize {
     let _i: isize = i as isize;
-    (_i & -_i) as usize
+    (_i & _i.wrapping_neg()) as usize
 }
 
 #[inline(always)]
-pub fn root(height: &usize) -> usize {
-    1 << height
+pub const fn root(height: &usize) -> usize {
+    1 << *height
+}
+
+#[inline(always)]
+pub const fn up(index: usize) -> usize {
+    let lsb: usize = lsb(index);
+    (index | lsb << 1) ^ lsb
+}
+
+// Transition downward to next 'lsb namespace'
+#[inline(always)]
+pub const fn down(index: usize, side: NodeSide) -> usize {
+    let lsb: usize = lsb(index);
+    match side {
+        NodeSide::Left => (index | lsb >> 1) ^ lsb,
+        NodeSide::Right => (index | lsb) ^ lsb >> 1
+    }
+}
+
+#[inline(always)]
+pub const fn left(index: usize) -> usize {
+    let step: usize = lsb(index) << 1;
+    index - if step < index { step } else { index }
+}
+
+#[inline(always)]
+pub const fn right(index: usize) -> usize {
+    index + (lsb(index) << 1)
+}
+
+// Storage order is in-order, so neighbours are a single slot away
+#[inline(always)]
+pub const fn next(index: usize) -> usize {
+    index + 1
+}
+
+#[inline(always)]
+pub const fn prev(index: usize) -> usize {
+    index.saturating_sub(1)
+}
+
+#[inline(always)]
+pub const fn sibling(index: usize) -> usize {
+    index ^ lsb(index) << 1
+}
+
+#[inline(always)]
+pub const fn step(index: usize, direction: Direction) -> usize {
+    match direction {
+        Direction::Up => up(index),
+        Direction::Down(side) => down(index, side),
+        Direction::Left => left(index),
+        Direction::Right => right(index),
+        Direction::Next => next(index),
+        Direction::Prev => prev(index)
+    }
+}
+
+// The side is encoded by the bit directly above the lsb
+#[inline(always)]
+pub const fn node_side(index: usize) -> NodeSide {
+    match index & lsb(index) << 1 {
+        0 => NodeSide::Left,
+        _ => NodeSide::Right
+    }
+}
+
+#[inline(always)]
+pub const fn node_type(index: usize) -> NodeType {
+    match index & 1 {
+        0 => NodeType::Node,
+        _ => NodeType::Leaf
+    }
+}
+
+// Ancestors keep every bit above their level, clear
+// every bit below it and mark the level itself
+#[inline(always)]
+pub const fn ancestor(index: usize, level: usize) -> usize {
+    match level < usize::BITS as usize {
+        true => (index >> level >> 1 << level << 1) | 1 << level,
+        false => 0
+    }
+}
+
+#[inline(always)]
+fn ancestor_of<I: Index>(index: I, level: usize) -> I {
+    match level < I::BITS as usize {
+        true => (index >> (level as u32) >> 1 << (level as u32) << 1) | I::ONE << (level as u32),
+        false => I::ZERO
+    }
 }
 
 /*################################
@@ -44,10 +136,11 @@ pub fn root(height: &usize) -> usize {
 ################################*/
 
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
-pub enum FenwickTreeError {
+pub enum FenwickTreeError<I = usize> {
     Full,
     Empty,
-    OutOfBounds{index: usize, length: usize}
+    Overflow,
+    OutOfBounds{index: I, length: usize}
 }
 
 /*################################
@@ -55,57 +148,156 @@ pub enum FenwickTreeError {
 ################################*/
 
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Length)]
-#[length_method(self.lsb)]
-pub struct IndexView {
-    pub(crate) index: usize,
-    pub(crate) lsb: usize
+#[length_method(self.lsb.as_usize())]
+pub struct IndexView<I: Index = usize> {
+    pub(crate) index: I,
+    pub(crate) lsb: I
 }
 
-impl IndexView {
-    fn new(index: usize) -> Self {
+impl<I: Index> IndexView<I> {
+    fn new(index: I) -> Self {
         IndexView {
             index: index,
-            lsb: lsb(index)
+            lsb: index.lsb()
         }
     }
 
-    fn update(&mut self, new: usize) -> &mut Self {
+    fn update(&mut self, new: I) -> &mut Self {
         self.index = new;
-        self.lsb = lsb(new);
+        self.lsb = new.lsb();
 
         self
     }
+
+    // Mirrors the const usize navigation, but reports
+    // steps past the index width rather than wrapping
+    pub(crate) fn step(&self, direction: Direction) -> Option<I> {
+        let (index, lsb): (I, I) = (self.index, self.lsb);
+        match direction {
+            Direction::Up => Some((index | lsb << 1) ^ lsb),
+            Direction::Down(NodeSide::Left) => Some((index | lsb >> 1) ^ lsb),
+            Direction::Down(NodeSide::Right) => Some((index | lsb) ^ lsb >> 1),
+            Direction::Left => Some(index - (lsb << 1).min(index)),
+            Direction::Right => (lsb <= I::MAX >> 1).then(|| lsb << 1).and_then(| step | index.checked_add(step)),
+            Direction::Next => index.checked_add(I::ONE),
+            Direction::Prev => Some(index.saturating_sub(I::ONE))
+        }
+    }
+
+    pub(crate) fn sibling(&self) -> I {
+        self.index ^ self.lsb << 1
+    }
+
+    // Levels count upward from the leaves (level 0)
+    pub fn level(&self) -> usize {
+        self.lsb.trailing_zeros() as usize
+    }
+
+    pub fn ancestor(&self, k: usize) -> I {
+        ancestor_of(self.index, self.level().saturating_add(k))
+    }
+
+    pub fn common_ancestor(&self, other: &Self) -> Self {
+        let highest_diff: usize = (I::BITS - (self.index ^ other.index).leading_zeros()).saturating_sub(1) as usize;
+        let level: usize = self.level().max(other.level()).max(highest_diff);
+
+        Self::new(ancestor_of(self.index, level))
+    }
+
+    pub fn is_ancestor_of(&self, other: &Self) -> bool {
+        other.index != I::ZERO && self.lsb > other.lsb && ancestor_of(other.index, self.level()) == self.index
+    }
+
+    pub fn subtree_range(&self) -> RangeInclusive<I> {
+        (self.index ^ self.lsb) + I::ONE..=self.index | self.lsb.saturating_sub(I::ONE)
+    }
 }
 
-impl PartialEq<usize> for IndexView {
-    fn eq(&self, other: &usize) -> bool {
+impl<I: Index> PartialEq<I> for IndexView<I> {
+    fn eq(&self, other: &I) -> bool {
         &self.index == other
     }
 }
 
+/*################################
+           Walker Paths
+################################*/
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum WalkerPath<I: Index = usize> {
+    Index(I),
+    Tree(TreePath)
+}
+
+impl<I: Index> WalkerPath<I> {
+    // Paths deeper than the tree resolve to index 0, which every walker
+    // treats as out of bounds. Paths past the index width don't resolve
+    pub fn resolve(self, height: usize) -> Option<I> {
+        match self {
+            WalkerPath::Index(index) => Some(index),
+            WalkerPath::Tree(path) => match path.to_index(height) {
+                Some(index) => I::from_usize(index),
+                None => Some(I::ZERO)
+            }
+        }
+    }
+}
+
+impl<I: Index> From<TreePath> for WalkerPath<I> {
+    fn from(path: TreePath) -> Self {
+        WalkerPath::Tree(path)
+    }
+}
+
+impl TreePath {
+    pub fn from_index(index: usize, height: usize) -> Option<Self> {
+        let tz: usize = index.trailing_zeros() as usize;
+        if index == 0 || tz > height || index.checked_shr(height as u32 + 1).unwrap_or(0) != 0 {
+            return None;
+        }
+
+        Some(Self {
+            steps: index.checked_shr(tz as u32 + 1).unwrap_or(0),
+            depth: height - tz
+        })
+    }
+
+    pub fn to_index(&self, height: usize) -> Option<usize> {
+        let offset: usize = height.checked_sub(self.depth)?;
+        Some((self.steps << 1 | 1) << offset)
+    }
+}
+
 /*################################
            Tree Walkers
 ################################*/
 
 #[derive(Debug, Clone, PartialEq, Length)]
 #[length_method(self.length)]
-pub struct VirtualTreeView {
+pub struct VirtualTreeView<I: Index = usize> {
     length: usize,
+    pub curr: IndexView<I>
+}
+
+// Fixed-length view whose navigation is usable in const contexts
+#[derive(Debug, Copy, Clone, PartialEq, Length)]
+#[length_method(LEN)]
+pub struct ConstTreeView<const LEN: usize> {
     pub curr: IndexView
 }
 
 #[derive(Debug, Clone, PartialEq, Length)]
 #[length_method(self.collection.length())]
-pub struct StatefulTreeView<'a, C: ?Sized + Length> {
+pub struct StatefulTreeView<'a, C: ?Sized + Length, I: Index = usize> {
     collection: &'a C,
-    pub curr: IndexView
+    pub curr: IndexView<I>
 }
 
 #[derive(Debug, PartialEq, Length)]
 #[length_method(self.collection.length())]
-pub struct StatefulTreeViewMut<'a, C: ?Sized + Length> {
+pub struct StatefulTreeViewMut<'a, C: ?Sized + Length, I: Index = usize> {
     collection: &'a mut C,
-    pub curr: IndexView
+    pub curr: IndexView<I>
 }
 
 /*################################
@@ -114,41 +306,30 @@ pub struct StatefulTreeViewMut<'a, C: ?Sized + Length> {
 
 impl From<usize> for NodeSide {
     fn from(index: usize) -> Self {
-        let index_lsb: usize = lsb(index);
-        match index >> index_lsb & 1 {
-            0 => NodeSide::Left,
-            1 => NodeSide::Right,
-            _ => unreachable!("Invariant")
-        }
+        node_side(index)
     }
 }
 
-impl From<&IndexView> for NodeSide {
-    fn from(view: &IndexView) -> Self {
-        match view.index >> view.lsb & 1 {
-            0 => NodeSide::Left,
-            1 => NodeSide::Right,
-            _ => unreachable!("Invariant")
+impl<I: Index> From<&IndexView<I>> for NodeSide {
+    fn from(view: &IndexView<I>) -> Self {
+        match view.index & view.lsb << 1 == I::ZERO {
+            true => NodeSide::Left,
+            false => NodeSide::Right
         }
     }
 }
 
 impl From<usize> for NodeType {
     fn from(index: usize) -> Self {
-        match index & 1 {
-            0 => NodeType::Node,
-            1 => NodeType::Leaf,
-            _ => unreachable!("Invariant")
-        }
+        node_type(index)
     }
 }
 
-impl From<&IndexView> for NodeType {
-    fn from(view: &IndexView) -> Self {
-        match view.index & 1 {
-            0 => NodeType::Node,
-            1 => NodeType::Leaf,
-            _ => unreachable!("Invariant")
+impl<I: Index> From<&IndexView<I>> for NodeType {
+    fn from(view: &IndexView<I>) -> Self {
+        match view.index & I::ONE == I::ZERO {
+            true => NodeType::Node,
+            false => NodeType::Leaf
         }
     }
 }
@@ -157,28 +338,37 @@ impl From<&IndexView> for NodeType {
          IndexView Impls
 ################################*/
 
-impl_op!{BitOr<usize>, bitor, |, usize}
-impl_op!{BitXor<usize>, bitxor, ^, usize}
-impl_op!{BitAnd<usize>, bitand, &, usize}
-impl_op!{Add<usize>, add, +, usize}
-impl_op!{Sub<usize>, sub, -, usize}
-impl_op_assign!{BitOrAssign<usize>, bitor_assign, |=, usize}
-impl_op_assign!{BitXorAssign<usize>, bitxor_assign, ^=, usize}
-impl_op_assign!{BitAndAssign<usize>, bitand_assign, &=, usize}
-impl_op_assign!{AddAssign<usize>, add_assign, +=, usize}
-impl_op_assign!{SubAssign<usize>, sub_assign, -=, usize}
+impl_op!{BitOr, bitor, |}
+impl_op!{BitXor, bitxor, ^}
+impl_op!{BitAnd, bitand, &}
+impl_op!{Add, add, +}
+impl_op!{Sub, sub, -}
+impl_op_assign!{BitOrAssign, bitor_assign, |}
+impl_op_assign!{BitXorAssign, bitxor_assign, ^}
+impl_op_assign!{BitAndAssign, bitand_assign, &}
+impl_op_assign!{AddAssign, add_assign, +}
+impl_op_assign!{SubAssign, sub_assign, -}
 
 /*################################
            Walker Impls
 ################################*/
 
 impl_walker!{aux_methods(type = VirtualTreeView)}
+impl_walker!{aux_methods(type = ConstTreeView)}
 impl_walker!{aux_methods(type = StatefulTreeView)}
 impl_walker!{aux_methods(type = StatefulTreeViewMut: mut)}
 
 impl_walker!{
     trait(
         type = VirtualTreeView,
+        output = I,
+        return_wrapper = safe_tree_index!(virtual(self, #[ret]));
+    )
+}
+
+impl_walker!{
+    trait(
+        type = ConstTreeView,
         output = usize,
         return_wrapper = safe_tree_index!(virtual(self, #[ret]));
     )
@@ -206,4 +396,14 @@ impl_walker!{
         output = &'w mut C::Output,
         return_wrapper = safe_tree_index!(stateful(self, #[ret], mut));
     )
-}
\ No newline at end of file
+}
+impl<'a, C, I> StatefulTreeViewMut<'a, C, I> where
+    C: ?Sized + IndexedCollectionMut,
+    C::Output: Sized,
+    I: Index
+{
+    // Releases the walker, keeping the borrow of the current node
+    pub fn into_current_mut(self) -> Result<&'a mut C::Output, FenwickTreeError<I>> {
+        safe_tree_index!(stateful(self, self.curr.index, mut));
+    }
+}
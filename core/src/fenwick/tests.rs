@@ -41,6 +41,16 @@ mod auxiliary {
         assert_eq!(view.lsb, expected_lsb);
     }
 
+    #[test_case]
+    fn indexview_new_const() {
+        const NARROW: IndexView<u16> = IndexView::<u16>::new_const(12);
+        const WIDE: IndexView<u64> = IndexView::<u64>::new_const(1 << 40);
+
+        assert_eq!(NARROW, IndexView::new(12u16));
+        assert_eq!((WIDE.index, WIDE.lsb), (1 << 40, 1 << 40));
+        assert_eq!(IndexView::<u32>::new_const(0), IndexView::new(0u32));
+    }
+
     #[test_case(add, with(IndexView::new(2), verbatim(+), 4))]
     #[test_case(sub, with(IndexView::new(2), verbatim(-), 0))]
     #[test_case(bit_or, with(IndexView::new(2), verbatim(|), 2))]
@@ -71,20 +81,56 @@ mod auxiliary {
         assert_eq!(view, IndexView { index: index, lsb: index })
     }
 
+    #[test_case(leaf, with(1, 0, 1..=1))]
+    #[test_case(node, with(2, 1, 1..=3))]
+    #[test_case(upper_node, with(12, 2, 9..=15))]
+    #[test_case(index_zero, with(0, usize::BITS as usize, 1..=0))]
+    fn indexview_level_and_range(index: usize, expected_level: usize, expected_range: core::ops::RangeInclusive<usize>) {
+        let view = IndexView::new(index);
+
+        assert_eq!(view.level(), expected_level);
+        assert_eq!(view.subtree_range(), expected_range);
+    }
+
+    #[test_case]
+    fn indexview_ancestors() {
+        /*        4 <--------> 12
+               2 <-----> 6
+            1 <-> 3   5 <-> 7
+        */
+        assert_eq!(IndexView::new(5usize).ancestor(0), 5);
+        assert_eq!(IndexView::new(5usize).ancestor(1), 6);
+        assert_eq!(IndexView::new(5usize).ancestor(2), 4);
+        assert_eq!(IndexView::new(5usize).ancestor(3), 8);
+
+        assert_eq!(IndexView::new(1usize).common_ancestor(&IndexView::new(3usize)), IndexView::new(2usize));
+        assert_eq!(IndexView::new(3usize).common_ancestor(&IndexView::new(5usize)), IndexView::new(4usize));
+        assert_eq!(IndexView::new(2usize).common_ancestor(&IndexView::new(3usize)), IndexView::new(2usize));
+        assert_eq!(IndexView::new(7usize).common_ancestor(&IndexView::new(7usize)), IndexView::new(7usize));
+
+        assert!(IndexView::new(4usize).is_ancestor_of(&IndexView::new(7usize)));
+        assert!(!IndexView::new(4usize).is_ancestor_of(&IndexView::new(4usize)));
+        assert!(!IndexView::new(7usize).is_ancestor_of(&IndexView::new(4usize)));
+        assert!(!IndexView::new(2usize).is_ancestor_of(&IndexView::new(5usize)));
+        assert!(!IndexView::new(4usize).is_ancestor_of(&IndexView::new(0usize)));
+    }
+
     #[test_case]
     fn nodeside_conversion() {
         /*        4 <--------> 12
                2 <-----> 6
             1 <-> 3   5 <-> 7
         */
-        assert_eq!(NodeSide::from(&IndexView::new(1)), NodeSide::Left);
-        assert_eq!(NodeSide::from(&IndexView::new(2)), NodeSide::Left);
-        assert_eq!(NodeSide::from(&IndexView::new(3)), NodeSide::Right);
-        assert_eq!(NodeSide::from(&IndexView::new(4)), NodeSide::Left);
-        assert_eq!(NodeSide::from(&IndexView::new(5)), NodeSide::Left);
-        assert_eq!(NodeSide::from(&IndexView::new(6)), NodeSide::Right);
-        assert_eq!(NodeSide::from(&IndexView::new(7)), NodeSide::Right);
-        assert_eq!(NodeSide::from(&IndexView::new(8)), NodeSide::Left);
+        assert_eq!(NodeSide::from(&IndexView::new(1usize)), NodeSide::Left);
+        assert_eq!(NodeSide::from(&IndexView::new(2usize)), NodeSide::Left);
+        assert_eq!(NodeSide::from(&IndexView::new(3usize)), NodeSide::Right);
+        assert_eq!(NodeSide::from(&IndexView::new(4usize)), NodeSide::Left);
+        assert_eq!(NodeSide::from(&IndexView::new(5usize)), NodeSide::Left);
+        assert_eq!(NodeSide::from(&IndexView::new(6usize)), NodeSide::Right);
+        assert_eq!(NodeSide::from(&IndexView::new(7usize)), NodeSide::Right);
+        assert_eq!(NodeSide::from(&IndexView::new(8usize)), NodeSide::Left);
+        assert_eq!(NodeSide::from(&IndexView::new(12usize)), NodeSide::Right);
+        assert_eq!(NodeSide::from(24), NodeSide::Right);
     }
     
     #[test_case]
@@ -93,14 +139,88 @@ mod auxiliary {
                2 <-----> 6
             1 <-> 3   5 <-> 7
         */
-        assert_eq!(NodeType::from(&IndexView::new(1)), NodeType::Leaf);
-        assert_eq!(NodeType::from(&IndexView::new(2)), NodeType::Node);
-        assert_eq!(NodeType::from(&IndexView::new(3)), NodeType::Leaf);
-        assert_eq!(NodeType::from(&IndexView::new(4)), NodeType::Node);
-        assert_eq!(NodeType::from(&IndexView::new(5)), NodeType::Leaf);
-        assert_eq!(NodeType::from(&IndexView::new(6)), NodeType::Node);
-        assert_eq!(NodeType::from(&IndexView::new(7)), NodeType::Leaf);
-        assert_eq!(NodeType::from(&IndexView::new(8)), NodeType::Node);
+        assert_eq!(NodeType::from(&IndexView::new(1usize)), NodeType::Leaf);
+        assert_eq!(NodeType::from(&IndexView::new(2usize)), NodeType::Node);
+        assert_eq!(NodeType::from(&IndexView::new(3usize)), NodeType::Leaf);
+        assert_eq!(NodeType::from(&IndexView::new(4usize)), NodeType::Node);
+        assert_eq!(NodeType::from(&IndexView::new(5usize)), NodeType::Leaf);
+        assert_eq!(NodeType::from(&IndexView::new(6usize)), NodeType::Node);
+        assert_eq!(NodeType::from(&IndexView::new(7usize)), NodeType::Leaf);
+        assert_eq!(NodeType::from(&IndexView::new(8usize)), NodeType::Node);
+    }
+
+    #[test_case]
+    fn tree_path_push_pop() {
+        let mut path = TreePath::root();
+        assert_eq!(path.pop(), None);
+
+        path.push(NodeSide::Left);
+        path.push(NodeSide::Right);
+        path.push(NodeSide::Right);
+
+        assert_eq!(path.depth(), 3);
+        assert_eq!(path.get(0), Some(NodeSide::Left));
+        assert_eq!(path.get(2), Some(NodeSide::Right));
+        assert_eq!(path.get(3), None);
+
+        assert_eq!(path.pop(), Some(NodeSide::Right));
+        assert_eq!(path.pop(), Some(NodeSide::Right));
+        assert_eq!(path.pop(), Some(NodeSide::Left));
+        assert!(path.is_root());
+    }
+
+    #[test_case]
+    fn tree_path_try_push() {
+        let mut path: TreePath = [NodeSide::Right; TreePath::MAX_DEPTH].into_iter().collect();
+
+        assert_eq!(path.try_push(NodeSide::Left), Err(FenwickTreeError::Overflow));
+        assert_eq!(path.depth(), TreePath::MAX_DEPTH);
+        assert_eq!(path.last(), Some(NodeSide::Right));
+    }
+
+    #[test_case(root, with(8, verbatim([])))]
+    #[test_case(left, with(4, verbatim([NodeSide::Left])))]
+    #[test_case(right, with(12, verbatim([NodeSide::Right])))]
+    #[test_case(left_right, with(6, verbatim([NodeSide::Left, NodeSide::Right])))]
+    #[test_case(leaf, with(7, verbatim([NodeSide::Left, NodeSide::Right, NodeSide::Right])))]
+    fn tree_path_index_conversion(index: usize, r#steps: _) {
+        let path: TreePath = r#steps.into_iter().collect();
+
+        assert_eq!(TreePath::from_index(index, 3), Some(path));
+        assert_eq!(path.to_index(3), Some(index));
+    }
+
+    #[test_case]
+    fn tree_path_index_conversion_out_of_tree() {
+        assert_eq!(TreePath::from_index(0, 3), None);
+        assert_eq!(TreePath::from_index(16, 3), None);
+        assert_eq!(TreePath::from_index(24, 3), None);
+
+        let path: TreePath = [NodeSide::Left; 4].into_iter().collect();
+        assert_eq!(path.to_index(3), None);
+    }
+
+    #[test_case]
+    fn tree_path_prefix() {
+        let parent: TreePath = [NodeSide::Left].into_iter().collect();
+        let child: TreePath = [NodeSide::Left, NodeSide::Right].into_iter().collect();
+        let other: TreePath = [NodeSide::Right, NodeSide::Right].into_iter().collect();
+
+        assert!(TreePath::root().is_ancestor_of(&child));
+        assert!(parent.is_ancestor_of(&child));
+        assert!(parent.is_prefix_of(&parent));
+        assert!(!parent.is_ancestor_of(&parent));
+        assert!(!parent.is_ancestor_of(&other));
+        assert!(!child.is_ancestor_of(&parent));
+        assert_eq!(child.parent(), Some(parent));
+    }
+
+    #[test_case]
+    fn tree_path_display() {
+        let path: TreePath = [NodeSide::Left, NodeSide::Right, NodeSide::Left].into_iter().collect();
+
+        assert_eq!(TreePath::root().to_string(), "/");
+        assert_eq!(path.to_string(), "/LRL");
     }
 }
 
@@ -172,6 +292,70 @@ mod walkers {
         assert_eq!(walker.curr, IndexView { index: 1, lsb: 1 });
     }
 
+    #[test_case(virtual_view, with(verbatim(VirtualTreeView), verbatim(probe), verbatim()))]
+    #[test_case(stateful_view, with(verbatim(StatefulTreeView<MockCollection>), verbatim(probe), verbatim(&)))]
+    #[test_case(stateful_view_mut, with(verbatim(StatefulTreeViewMut<MockCollection>), verbatim(probe), verbatim(&)))]
+    #[test_case(mut_stateful_view_mut, with(verbatim(StatefulTreeViewMut<MockCollection>), verbatim(probe_mut), verbatim(&mut)))]
+    fn probe_tree_path(r#walker_ty: _, r#probe_fn: _, r#output_ref: _) {
+        let mut inner: MockCollection = MockCollection::new(32);
+        let (mut walker, mut expected) = generate_walker!(r#walker_ty, inner, 1);
+
+        // Height 5 - the root (32) itself lies outside the collection
+        assert_eq!(walker.r#probe_fn(TreePath::root()), Err(FenwickTreeError::OutOfBounds { index: 32, length: 32 }));
+        assert_eq!(walker.r#probe_fn(TreePath::from_iter([NodeSide::Left; 6])), Err(FenwickTreeError::OutOfBounds { index: 0, length: 32 }));
+
+        assert_eq!(walker.r#probe_fn(TreePath::from_iter([NodeSide::Left])), Ok(r#output_ref expected[16]));
+        assert_eq!(walker.r#probe_fn(TreePath::from_iter([NodeSide::Left, NodeSide::Right])), Ok(r#output_ref expected[24]));
+
+        assert_eq!(walker.curr, IndexView { index: 1, lsb: 1 });
+    }
+
+    #[test_case(virtual_view, with(verbatim(VirtualTreeView)))]
+    #[test_case(stateful_view, with(verbatim(StatefulTreeView<MockCollection>)))]
+    #[test_case(stateful_view_mut, with(verbatim(StatefulTreeViewMut<MockCollection>)))]
+    fn level_queries(r#walker_ty: _) {
+        let mut inner: MockCollection = MockCollection::new(32);
+        let (mut walker, _) = generate_walker!(r#walker_ty, inner, 5);
+
+        // Height 5 - the root (32) lies outside the collection
+        assert_eq!(walker.depth(), Ok(5));
+        assert_eq!(walker.level_of(16), Ok(1));
+        assert_eq!(walker.level_of(0), Err(FenwickTreeError::OutOfBounds { index: 0, length: 32 }));
+
+        assert_eq!(walker.kth_ancestor(0), Ok(5));
+        assert_eq!(walker.kth_ancestor(1), Ok(6));
+        assert_eq!(walker.kth_ancestor(3), Ok(8));
+        assert_eq!(walker.kth_ancestor(5), Err(FenwickTreeError::OutOfBounds { index: 32, length: 32 }));
+        assert_eq!(walker.subtree_range(), 5..=5);
+
+        walker.seek(24);
+        assert_eq!(walker.depth(), Ok(2));
+        assert_eq!(walker.subtree_range(), 17..=31);
+    }
+
+    #[test_case(virtual_view, with(verbatim(VirtualTreeView)))]
+    #[test_case(stateful_view, with(verbatim(StatefulTreeView<MockCollection>)))]
+    #[test_case(stateful_view_mut, with(verbatim(StatefulTreeViewMut<MockCollection>)))]
+    fn ancestor_queries(r#walker_ty: _) {
+        let mut inner: MockCollection = MockCollection::new(32);
+        let (walker, _) = generate_walker!(r#walker_ty, inner, 1);
+
+        assert_eq!(walker.lowest_common_ancestor(5, 7), Ok(6));
+        assert_eq!(walker.lowest_common_ancestor(1, 31), Ok(16));
+        assert_eq!(walker.lowest_common_ancestor(6, 7), Ok(6));
+        assert_eq!(walker.lowest_common_ancestor(1, 32), Err(FenwickTreeError::OutOfBounds { index: 32, length: 32 }));
+
+        assert_eq!(walker.distance(5, 7), Ok(2));
+        assert_eq!(walker.distance(1, 31), Ok(8));
+        assert_eq!(walker.distance(6, 7), Ok(1));
+        assert_eq!(walker.distance(3, 3), Ok(0));
+
+        assert!(walker.is_ancestor_of(16, 9));
+        assert!(!walker.is_ancestor_of(9, 16));
+        assert!(walker.is_ancestor_of(8, 9));
+        assert!(!walker.is_ancestor_of(4, 9));
+    }
+
     #[test_case(virtual_view, with(verbatim(VirtualTreeView)))]
     #[test_case(stateful_view, with(verbatim(StatefulTreeView<MockCollection>)))]
     #[test_case(stateful_view_mut, with(verbatim(StatefulTreeViewMut<MockCollection>)))]
@@ -181,7 +365,7 @@ mod walkers {
         let (mut walker, _) = generate_walker!(r#walker_ty, inner, 1);
         walker.traverse(Direction::Left);
 
-        assert_eq!(walker.curr, IndexView { index: 0, lsb: 1 });
+        assert_eq!(walker.curr, IndexView { index: 0, lsb: 0 });
     }
 
     #[test_case(virtual_view, with(verbatim(VirtualTreeView)))]
@@ -208,6 +392,25 @@ mod walkers {
         assert_eq!(walker.curr, IndexView { index: 0, lsb: 0 });
     }
 
+    #[test_case(virtual_view, with(verbatim(VirtualTreeView)))]
+    #[test_case(stateful_view, with(verbatim(StatefulTreeView<MockCollection>)))]
+    #[test_case(stateful_view_mut, with(verbatim(StatefulTreeViewMut<MockCollection>)))]
+    fn seek_tree_path(r#walker_ty: _) {
+        let mut inner: MockCollection = MockCollection::new(32);
+
+        let (mut walker, _) = generate_walker!(r#walker_ty, inner, 31);
+
+        walker.seek(TreePath::from_iter([NodeSide::Left, NodeSide::Left, NodeSide::Right]));
+        assert_eq!(walker.curr, IndexView { index: 12, lsb: 4 });
+        assert_eq!(TreePath::from_index(walker.index(), walker.height()).map(|path| path.to_string()), Some("/LLR".to_string()));
+        assert_eq!(walker.path().map(|path| path.to_string()), Ok("/LLR".to_string()));
+
+        walker.reset();
+        assert_eq!(walker.path(), Err(FenwickTreeError::OutOfBounds { index: 32, length: 32 }));
+        walker.seek(16);
+        assert_eq!(walker.path().map(|path| path.to_string()), Ok("/L".to_string()));
+    }
+
     #[test_case(virtual_view, with(verbatim(VirtualTreeView), verbatim(current), verbatim()))]
     #[test_case(stateful_view, with(verbatim(StatefulTreeView<MockCollection>), verbatim(current), verbatim(&)))]
     #[test_case(stateful_view_mut, with(verbatim(StatefulTreeViewMut<MockCollection>), verbatim(current), verbatim(&)))]
@@ -244,6 +447,258 @@ mod walkers {
         walker.curr = IndexView::new(32);
         assert_eq!(walker.r#sibling_fn(), Err(FenwickTreeError::OutOfBounds { index: 96, length: 32 }));
     }
+
+    #[test_case(virtual_view, with(verbatim(VirtualTreeView), verbatim(peek), verbatim()))]
+    #[test_case(stateful_view, with(verbatim(StatefulTreeView<MockCollection>), verbatim(peek), verbatim(&)))]
+    #[test_case(stateful_view_mut, with(verbatim(StatefulTreeViewMut<MockCollection>), verbatim(peek), verbatim(&)))]
+    #[test_case(mut_stateful_view_mut, with(verbatim(StatefulTreeViewMut<MockCollection>), verbatim(peek_mut), verbatim(&mut)))]
+    fn in_order_neighbours(r#walker_ty: _, r#peek_fn: _, r#output_ref: _) {
+        let mut inner: MockCollection = MockCollection::new(32);
+        let (mut walker, mut expected) = generate_walker!(r#walker_ty, inner, 8);
+
+        assert_eq!(walker.r#peek_fn(Direction::Next), Ok(r#output_ref expected[9]));
+        assert_eq!(walker.r#peek_fn(Direction::Prev), Ok(r#output_ref expected[7]));
+
+        walker.seek(1);
+        assert_eq!(walker.r#peek_fn(Direction::Prev), Err(FenwickTreeError::OutOfBounds { index: 0, length: 32 }));
+
+        walker.seek(31);
+        assert_eq!(walker.r#peek_fn(Direction::Next), Err(FenwickTreeError::OutOfBounds { index: 32, length: 32 }));
+    }
+
+    #[test_case(virtual_view, with(verbatim(VirtualTreeView)))]
+    #[test_case(stateful_view, with(verbatim(StatefulTreeView<MockCollection>)))]
+    #[test_case(stateful_view_mut, with(verbatim(StatefulTreeViewMut<MockCollection>)))]
+    fn traverse_in_order(r#walker_ty: _) {
+        let mut inner: MockCollection = MockCollection::new(11);
+        let (mut walker, _) = generate_walker!(r#walker_ty, inner, 1);
+
+        let mut visited: Vec<usize> = Vec::new();
+        while walker.current().is_ok() {
+            visited.push(walker.index());
+            walker.traverse(Direction::Next);
+        }
+        assert_eq!(visited, (1..11).collect::<Vec<usize>>());
+
+        walker.traverse(Direction::Prev);
+        assert_eq!(walker.curr, IndexView { index: 10, lsb: 2 });
+    }
+
+    #[test_case]
+    fn narrow_index() {
+        let inner: MockCollection = MockCollection::new(32);
+        let mut walker: StatefulTreeView<MockCollection, u16> = StatefulTreeView::new(&inner, 12u16).unwrap();
+
+        assert_eq!(walker.peek(Direction::Up), Ok(&8));
+        assert_eq!(walker.probe(TreePath::from_iter([NodeSide::Left])), Ok(&16));
+        assert_eq!(walker.lowest_common_ancestor(9, 14), Ok(12u16));
+
+        walker.seek(0xC000u16);
+        assert_eq!(walker.peek(Direction::Right), Err(FenwickTreeError::Overflow));
+
+        walker.seek(0x8000u16);
+        assert_eq!(walker.peek(Direction::Right), Err(FenwickTreeError::Overflow));
+
+        walker.traverse(Direction::Right);
+        assert_eq!(walker.curr, IndexView { index: 0u16, lsb: 0 });
+    }
+
+    #[test_case]
+    fn narrow_index_paths() {
+        let walker: VirtualTreeView<u16> = VirtualTreeView::new(&MockCollection::new(1 << 20), 1u16).unwrap();
+
+        // Height 20 - the root itself lies past the u16 range
+        assert_eq!(walker.probe(TreePath::root()), Err(FenwickTreeError::Overflow));
+        assert_eq!(walker.probe(TreePath::from_iter([NodeSide::Left; 5])), Ok(1u16 << 15));
+    }
+
+    #[test_case]
+    fn const_tree_view() {
+        type View = ConstTreeView<32>;
+
+        // Leftmost descent from the root, resolved at compile time
+        const ROUTE: [usize; 5] = {
+            let mut route: [usize; 5] = [0; 5];
+            let mut view: View = View::at_root().stepped(Direction::Down(NodeSide::Left));
+            let mut i: usize = 0;
+            while i < route.len() {
+                route[i] = view.index();
+                view = view.stepped(Direction::Down(NodeSide::Left));
+                i += 1;
+            }
+
+            route
+        };
+
+        const ROOT: Result<usize, FenwickTreeError> = View::at_root().probe_const(View::ROOT);
+        const SIBLING: Result<usize, FenwickTreeError> = match View::new(2) {
+            Ok(view) => view.sibling_const(),
+            Err(err) => Err(err)
+        };
+
+        assert_eq!(View::HEIGHT, 5);
+        assert_eq!(ROUTE, [16, 8, 4, 2, 1]);
+        assert_eq!(ROOT, Err(FenwickTreeError::OutOfBounds { index: 32, length: 32 }));
+        assert_eq!(SIBLING, Ok(6));
+    }
+
+    #[test_case]
+    fn const_tree_view_walker() {
+        let mut walker: ConstTreeView<32> = ConstTreeView::new(12).unwrap();
+
+        assert_eq!(walker.length(), 32);
+        assert_eq!(walker.peek(Direction::Up), walker.peek_const(Direction::Up));
+        assert_eq!(walker.peek(Direction::Down(NodeSide::Right)), Ok(14));
+
+        walker.traverse(Direction::Right);
+        assert_eq!(walker, ConstTreeView::<32>::new(12).unwrap().stepped(Direction::Right));
+        assert_eq!(walker.current(), Ok(20));
+    }
+}
+
+#[test_suite]
+mod traversals {
+    use super::*;
+    use sith::test_case;
+
+    /*                  8
+               4 <-----------> (12)
+           2 <---> 6        10 <---> (14)
+         1 <> 3  5 <> 7   9 <> (11)
+    */
+    const LENGTH: usize = 11;
+
+    macro_rules! collect_indices {
+        ($traversal:expr) => {
+            $traversal.map(| step | step.index).collect::<Vec<usize>>()
+        };
+    }
+
+    #[test_case(pre_order, with(verbatim(pre_order), verbatim([8, 4, 2, 1, 3, 6, 5, 7, 10, 9])))]
+    #[test_case(in_order, with(verbatim(in_order), verbatim([1, 2, 3, 4, 5, 6, 7, 8, 9, 10])))]
+    #[test_case(post_order, with(verbatim(post_order), verbatim([1, 3, 2, 5, 7, 6, 4, 9, 10, 8])))]
+    #[test_case(level_order, with(verbatim(level_order), verbatim([8, 4, 12, 2, 6, 10, 1, 3, 5, 7, 9]
+        .into_iter().filter(| index | *index < LENGTH).collect::<Vec<usize>>())))]
+    fn virtual_view(r#order_fn: _, r#expected: _) {
+        let walker: VirtualTreeView = VirtualTreeView::new(&MockCollection::new(LENGTH), 8).unwrap();
+        assert_eq!(collect_indices!(walker.r#order_fn()), r#expected.to_vec());
+    }
+
+    #[test_case(pre_order, with(verbatim(pre_order), verbatim([6, 5, 7])))]
+    #[test_case(in_order, with(verbatim(in_order), verbatim([5, 6, 7])))]
+    #[test_case(post_order, with(verbatim(post_order), verbatim([5, 7, 6])))]
+    #[test_case(level_order, with(verbatim(level_order), verbatim([6, 5, 7])))]
+    fn stateful_view_subtree(r#order_fn: _, r#expected: _) {
+        let collection: [usize; LENGTH] = core::array::from_fn(| i | i * 10);
+        let walker: StatefulTreeView<[usize; LENGTH]> = StatefulTreeView::new(&collection, 6).unwrap();
+
+        let nodes: Vec<usize> = walker.r#order_fn().map(| step | *step.node).collect();
+        assert_eq!(nodes, r#expected.map(| index | index * 10).to_vec());
+    }
+
+    #[test_case(pre_order, with(verbatim(pre_order), verbatim([4, 2, 1, 3, 6, 5, 7])))]
+    #[test_case(in_order, with(verbatim(in_order), verbatim([1, 2, 3, 4, 5, 6, 7])))]
+    #[test_case(post_order, with(verbatim(post_order), verbatim([1, 3, 2, 5, 7, 6, 4])))]
+    #[test_case(level_order, with(verbatim(level_order), verbatim([4, 2, 6, 1, 3, 5, 7])))]
+    fn phantom_root(r#order_fn: _, r#expected: _) {
+        // Root (8) lies outside the collection - only its left subtree exists
+        let walker: VirtualTreeView = VirtualTreeView::new(&MockCollection::new(8), 8).unwrap();
+        assert_eq!(collect_indices!(walker.r#order_fn()), r#expected.to_vec());
+    }
+
+    #[test_case]
+    fn step_metadata() {
+        let walker: VirtualTreeView = VirtualTreeView::new(&MockCollection::new(LENGTH), 4).unwrap();
+        let steps: Vec<Step<usize>> = walker.pre_order().take(3).collect();
+
+        assert_eq!(steps[0], Step { index: 4, node: 4, node_type: NodeType::Node, node_side: NodeSide::Left });
+        assert_eq!(steps[1], Step { index: 2, node: 2, node_type: NodeType::Node, node_side: NodeSide::Left });
+        assert_eq!(steps[2], Step { index: 1, node: 1, node_type: NodeType::Leaf, node_side: NodeSide::Left });
+    }
+
+    #[test_case]
+    fn mutable_traversal() {
+        let mut collection: [usize; LENGTH] = [0; LENGTH];
+        let mut walker: StatefulTreeViewMut<[usize; LENGTH]> = StatefulTreeViewMut::new(&mut collection, 8).unwrap();
+
+        let mut position: usize = 0;
+        walker.level_order_mut().for_each_mut(| step | {
+            position += 1;
+            *step.node = position;
+        });
+
+        assert_eq!(collection, [0, 6, 3, 7, 2, 8, 4, 9, 1, 10, 5]);
+    }
+
+    #[test_case]
+    fn out_of_bounds_root() {
+        let mut walker: VirtualTreeView = VirtualTreeView::new(&MockCollection::new(LENGTH), 8).unwrap();
+
+        walker.seek(0);
+        assert_eq!(walker.pre_order().next(), None);
+
+        // Phantom roots still yield their real descendants
+        walker.seek(32);
+        assert_eq!(walker.in_order().next().map(| step | step.index), Some(1));
+
+        walker.seek(48);
+        assert_eq!(walker.in_order().next(), None);
+    }
+}
+
+#[test_suite]
+mod fenwick_paths {
+    use super::*;
+    use sith::test_case;
+
+    #[test_case(leaf, with(5, verbatim([5, 6, 8])))]
+    #[test_case(node, with(6, verbatim([6, 8])))]
+    #[test_case(last, with(15, verbatim([])))]
+    #[test_case(zero, with(0, verbatim([])))]
+    fn update_path(index: usize, r#expected: _) {
+        let walker = VirtualTreeView::new(&MockCollection::new(15), 1).unwrap();
+        let expected: Vec<usize> = r#expected.to_vec();
+
+        assert_eq!(walker.update_path(index).collect::<Vec<usize>>(), expected);
+        assert_eq!(IndexView::new(index).update_path(15).collect::<Vec<usize>>(), expected);
+    }
+
+    #[test_case(leaf, with(7, verbatim([7, 6, 4])))]
+    #[test_case(node, with(12, verbatim([12, 8])))]
+    #[test_case(out_of_bounds, with(15, verbatim([])))]
+    #[test_case(zero, with(0, verbatim([])))]
+    fn query_path(index: usize, r#expected: _) {
+        let walker = VirtualTreeView::new(&MockCollection::new(15), 1).unwrap();
+        assert_eq!(walker.query_path(index).collect::<Vec<usize>>(), r#expected.to_vec());
+    }
+
+    #[test_case]
+    fn stateful_paths() {
+        let mut collection: [usize; 16] = [0; 16];
+        let mut walker: StatefulTreeViewMut<[usize; 16]> = StatefulTreeViewMut::new(&mut collection, 1).unwrap();
+
+        // Point updates followed by a prefix sum - a plain Fenwick tree
+        for (index, value) in [(3, 5), (6, 2), (9, 7)] {
+            walker.update_path_mut(index).for_each_mut(| node | *node += value);
+        }
+
+        assert_eq!(walker.query_path(8).sum::<usize>(), 7);
+        assert_eq!(walker.query_path(15).sum::<usize>(), 14);
+        assert_eq!(StatefulTreeView::new(&collection, 1usize).unwrap().query_path(2).sum::<usize>(), 0);
+    }
+
+    #[test_case]
+    fn narrow_index() {
+        let walker: VirtualTreeView<u16> = VirtualTreeView::new(&MockCollection::new(11), 8).unwrap();
+        assert_eq!(walker.in_order().map(| step | step.index).collect::<Vec<usize>>(), (1..11).collect::<Vec<usize>>());
+        assert_eq!(walker.update_path(3).collect::<Vec<usize>>(), [3, 4, 8]);
+
+        let mut collection: [usize; 16] = [0; 16];
+        let mut walker: StatefulTreeViewMut<[usize; 16], u32> = StatefulTreeViewMut::new(&mut collection, 1).unwrap();
+        walker.update_path_mut(3).for_each_mut(| node | *node += 5);
+        assert_eq!(walker.query_path(8).sum::<usize>(), 5);
+        assert_eq!(IndexView::new(3u64).update_path(16).collect::<Vec<usize>>(), [3, 4, 8]);
+    }
 }
 
 mod helper_functions {
@@ -280,12 +735,22 @@ mod helper_functions {
         for i in 0..ITERATIONS {
             let val: usize = randomness.next_u64() as usize;
             assert_eq!(
-                fenwick::compat::height(val), (val as f64).log(2.0).floor() as usize,
+                fenwick::compat::height(val), fenwick::height(val),
                 "Failed at iteration {} with value: {}, seed: {}", i, val, *SEED
             );
         }
     }
 
+    #[test]
+    fn height_edges() {
+        const HEIGHTS: [usize; 5] = [
+            fenwick::height(0), fenwick::height(1), fenwick::height(2),
+            fenwick::height(usize::MAX >> 1), fenwick::height(usize::MAX)
+        ];
+
+        assert_eq!(HEIGHTS, [0, 0, 1, usize::BITS as usize - 2, usize::BITS as usize - 1]);
+    }
+
     #[test]
     fn root() {
         let mut randomness: SmallRng = SmallRng::seed_from_u64(*SEED);
@@ -1,27 +1,143 @@
-use core::ops::{Index, IndexMut};
+use core::fmt::Debug;
+use core::hash::Hash;
+use core::ops::{
+    self, IndexMut,
+    BitAnd, BitOr, BitXor,
+    Shl, Shr, Add, Sub
+};
 use arborist_proc::{
     impl_insertable_collection,
     impl_length
 };
 
 pub use crate::tree::Height;
+use super::{WalkerPath, IndexView};
 
-#[cfg(feature = "no_float")]
-pub use compat::*;
-
-#[cfg(all(not(feature = "no_float"), not(target_pointer_width = "64")))]
+// Integer-only so that the index algebra stays usable in const contexts
 #[inline(always)]
-pub fn height(length: &usize) -> usize {
-    (length as f32).log2().floor() as usize
+pub const fn height(length: usize) -> usize {
+    match length {
+        0 => 0,
+        _ => (usize::BITS - 1 - length.leading_zeros()) as usize
+    }
 }
 
-#[cfg(all(not(feature = "no_float"), target_pointer_width = "64"))]
-#[inline(always)]
-pub fn height(length: usize) -> usize {
-    (length as f64).log2().floor() as usize
+// Former home of the float-free height, which is now the only one
+pub mod compat {
+    pub use super::height;
+}
+
+// Integer widths usable for walker and tree indices. Narrower widths
+// shrink walker state; anything past the width reports an overflow
+pub trait Index: Copy + Debug + Default + Hash + Ord
+    + BitAnd<Output = Self> + BitOr<Output = Self> + BitXor<Output = Self>
+    + Shl<u32, Output = Self> + Shr<u32, Output = Self>
+    + Add<Output = Self> + Sub<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const MAX: Self;
+    const BITS: u32;
+
+    fn from_usize(value: usize) -> Option<Self>;
+    fn as_usize(self) -> usize;
+
+    fn lsb(self) -> Self;
+    fn trailing_zeros(self) -> u32;
+    fn leading_zeros(self) -> u32;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn saturating_add(self, rhs: Self) -> Self;
+    fn saturating_sub(self, rhs: Self) -> Self;
+    fn checked_shl(self, rhs: u32) -> Option<Self>;
+    fn checked_shr(self, rhs: u32) -> Option<Self>;
 }
 
-pub trait IndexedCollection: Index<usize> + Length {}
+macro_rules! impl_index {
+    ($($width:ty),+) => {
+        $(
+            impl Index for $width {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const MAX: Self = <$width>::MAX;
+                const BITS: u32 = <$width>::BITS;
+
+                #[inline(always)]
+                fn from_usize(value: usize) -> Option<Self> {
+                    Self::try_from(value).ok()
+                }
+
+                // Indices always address a collection, so never exceed usize
+                #[inline(always)]
+                fn as_usize(self) -> usize {
+                    self as usize
+                }
+
+                #[inline(always)]
+                fn lsb(self) -> Self {
+                    self & self.wrapping_neg()
+                }
+
+                #[inline(always)]
+                fn trailing_zeros(self) -> u32 {
+                    <$width>::trailing_zeros(self)
+                }
+
+                #[inline(always)]
+                fn leading_zeros(self) -> u32 {
+                    <$width>::leading_zeros(self)
+                }
+
+                #[inline(always)]
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$width>::checked_add(self, rhs)
+                }
+
+                #[inline(always)]
+                fn saturating_add(self, rhs: Self) -> Self {
+                    <$width>::saturating_add(self, rhs)
+                }
+
+                #[inline(always)]
+                fn saturating_sub(self, rhs: Self) -> Self {
+                    <$width>::saturating_sub(self, rhs)
+                }
+
+                #[inline(always)]
+                fn checked_shl(self, rhs: u32) -> Option<Self> {
+                    <$width>::checked_shl(self, rhs)
+                }
+
+                #[inline(always)]
+                fn checked_shr(self, rhs: u32) -> Option<Self> {
+                    <$width>::checked_shr(self, rhs)
+                }
+            }
+
+            // Const counterpart of `IndexView::new()` - trait methods can't
+            // run in const contexts, so each width gets its own
+            impl IndexView<$width> {
+                pub const fn new_const(index: $width) -> Self {
+                    IndexView {
+                        index,
+                        lsb: index & index.wrapping_neg()
+                    }
+                }
+            }
+
+            // Concrete rather than blanket, so untyped literals still resolve
+            impl From<$width> for WalkerPath<$width> {
+                fn from(index: $width) -> Self {
+                    WalkerPath::Index(index)
+                }
+            }
+        )+
+    };
+}
+
+impl_index!(u16, u32, u64, usize);
+
+pub trait IndexedCollection: ops::Index<usize> + Length {}
 pub trait IndexedCollectionMut: IndexMut<usize> + IndexedCollection {}
 
 pub trait InsertableCollection: IndexedCollectionMut {
@@ -33,6 +149,10 @@ pub trait InsertableCollection: IndexedCollectionMut {
 
     fn set_length(&mut self, length: usize);
     fn has_capacity(&self) -> bool;
+    fn remaining_capacity(&self) -> usize;
+
+    fn as_slice(&self) -> &[Self::Output] where Self::Output: Sized;
+    fn as_mut_slice(&mut self) -> &mut [Self::Output] where Self::Output: Sized;
 }
 
 // Define as `length()` to avoid the fn
@@ -54,7 +174,7 @@ impl_length!(<T, const N: usize> &[T; N]);
 impl_length!(<T> &mut [T]);
 impl_length!(<T, const N: usize> &mut [T; N]);
 
-impl<C> IndexedCollection for C where C: Index<usize> + Length + ?Sized {}
+impl<C> IndexedCollection for C where C: ops::Index<usize> + Length + ?Sized {}
 impl<C> IndexedCollectionMut for C where C: IndexMut<usize> + IndexedCollection + Length +  ?Sized {}
 
 #[cfg(feature = "const_vec")]
@@ -100,47 +220,3 @@ mod std_vec {
 
     impl_insertable_collection!(<T> Vec<T>);
 }
-
-#[cfg(any(feature = "no_float", feature = "bench", test))]
-pub mod compat {
-    const USIZE_MIDPOINT: usize = (usize::BITS >> 1) as usize;
-
-    // "Optimization" - Jk halved the runtime
-    // TODO: Reduce branching misprediction (hardcoded branching/constants),
-    // reduce branching by one: on final 4 bits via. lookup table
-    #[inline]
-    pub fn height(length: usize) -> usize {
-        let mut cur: usize = USIZE_MIDPOINT;
-
-        match length >> cur {
-            1 => return cur,
-            0 => cur -= USIZE_MIDPOINT >> 1,
-            _ => cur += USIZE_MIDPOINT >> 1
-        };
-        match length >> cur {
-            1 => return cur,
-            0 => cur -= USIZE_MIDPOINT >> 2,
-            _ => cur += USIZE_MIDPOINT >> 2
-        };
-        #[cfg(any(target_pointer_width = "16", target_pointer_width = "32", target_pointer_width = "64"))]
-        match length >> cur {
-            1 => return cur,
-            0 => cur -= USIZE_MIDPOINT >> 3,
-            _ => cur += USIZE_MIDPOINT >> 3
-        };
-        #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
-        match length >> cur {
-            1 => return cur,
-            0 => cur -= USIZE_MIDPOINT >> 4,
-            _ => cur += USIZE_MIDPOINT >> 4
-        };
-        #[cfg(target_pointer_width = "64")]
-        match length >> cur {
-            1 => return cur,
-            0 => cur -= USIZE_MIDPOINT >> 5,
-            _ => cur += USIZE_MIDPOINT >> 5
-        };
-
-        cur
-    }
-}
\ No newline at end of file
@@ -0,0 +1,486 @@
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use crate::{NodeSide, NodeType};
+use super::{
+    IndexView, VirtualTreeView,
+    StatefulTreeView, StatefulTreeViewMut,
+    IndexedCollection, IndexedCollectionMut, Length, Index,
+    lsb
+};
+
+/*################################
+             Helpers
+################################*/
+
+// Nodes at or past the collection length are 'phantoms' - they're
+// never yielded, but traversals may pass through them to reach real
+// nodes in their left subtrees (e.g. index 12 in a collection of 11)
+
+#[inline(always)]
+fn lowest(index: usize) -> usize {
+    (index ^ lsb(index)) + 1
+}
+
+#[inline(always)]
+fn highest(index: usize) -> usize {
+    index | (lsb(index) - 1)
+}
+
+#[inline(always)]
+fn parent(index: usize) -> usize {
+    let index_lsb: usize = lsb(index);
+    (index | index_lsb << 1) ^ index_lsb
+}
+
+// First real node of a subtree in pre-order - phantoms
+// only ever have real nodes within their left subtree
+fn descend(mut index: usize, length: usize) -> Option<usize> {
+    if lowest(index) >= length {
+        return None;
+    }
+
+    while index >= length {
+        index -= lsb(index) >> 1;
+    }
+
+    Some(index)
+}
+
+// Next real node in level order, starting the search at `index`
+fn scan(root: usize, mut index: usize, mut level: usize, length: usize) -> Option<usize> {
+    let base: usize = root ^ lsb(root);
+    loop {
+        if index < length && index <= highest(root) {
+            return Some(index);
+        }
+
+        level = level.checked_sub(1)?;
+        index = base + (1 << level);
+    }
+}
+
+/*################################
+         Traversal Orders
+################################*/
+
+pub trait TraversalOrder {
+    fn first(root: usize, length: usize) -> Option<usize>;
+    fn next(current: usize, root: usize, length: usize) -> Option<usize>;
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PreOrder;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct InOrder;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PostOrder;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct LevelOrder;
+
+impl TraversalOrder for PreOrder {
+    fn first(root: usize, length: usize) -> Option<usize> {
+        descend(root, length)
+    }
+
+    fn next(current: usize, root: usize, length: usize) -> Option<usize> {
+        // Real nodes always have a real left child
+        if lsb(current) > 1 {
+            return Some(current - (lsb(current) >> 1));
+        }
+
+        let mut index: usize = current;
+        while index != root {
+            if NodeSide::from(index) == NodeSide::Left {
+                if let Some(next) = descend(index + (lsb(index) << 1), length) {
+                    return Some(next);
+                }
+            }
+
+            index = parent(index);
+        }
+
+        None
+    }
+}
+
+impl TraversalOrder for InOrder {
+    fn first(root: usize, length: usize) -> Option<usize> {
+        Some(lowest(root)).filter(| first | *first < length)
+    }
+
+    fn next(current: usize, root: usize, length: usize) -> Option<usize> {
+        Some(current + 1).filter(| next | *next < length && *next <= highest(root))
+    }
+}
+
+impl TraversalOrder for PostOrder {
+    fn first(root: usize, length: usize) -> Option<usize> {
+        InOrder::first(root, length)
+    }
+
+    fn next(current: usize, root: usize, length: usize) -> Option<usize> {
+        let mut index: usize = current;
+        while index != root {
+            let index_lsb: usize = lsb(index);
+            match NodeSide::from(index) {
+                NodeSide::Right => return Some(index - index_lsb),
+                NodeSide::Left => {
+                    // Lowest index of the right sibling's subtree
+                    let sibling_first: usize = index + index_lsb + 1;
+                    if sibling_first < length {
+                        return Some(sibling_first);
+                    }
+
+                    index += index_lsb;
+                    if index < length {
+                        return Some(index);
+                    }
+                }
+            }
+        }
+
+        None
+    }
+}
+
+impl TraversalOrder for LevelOrder {
+    fn first(root: usize, length: usize) -> Option<usize> {
+        scan(root, root, IndexView::new(root).level(), length)
+    }
+
+    fn next(current: usize, root: usize, length: usize) -> Option<usize> {
+        let level: usize = IndexView::new(current).level();
+        scan(root, current + (lsb(current) << 1), level, length)
+    }
+}
+
+/*################################
+            Traversal
+################################*/
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Step<T> {
+    pub index: usize,
+    pub node: T,
+    pub node_type: NodeType,
+    pub node_side: NodeSide
+}
+
+impl<T> Step<T> {
+    fn new(index: usize, node: T) -> Self {
+        Self {
+            index,
+            node,
+            node_type: NodeType::from(index),
+            node_side: NodeSide::from(index)
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Traversal<S, O: TraversalOrder> {
+    source: S,
+    root: usize,
+    length: usize,
+    next: Option<usize>,
+    order: PhantomData<O>
+}
+
+impl<S, O: TraversalOrder> Traversal<S, O> {
+    pub(crate) fn new(source: S, root: usize, length: usize) -> Self {
+        Self {
+            source,
+            root,
+            length,
+            next: (root > 0).then(|| O::first(root, length)).flatten(),
+            order: PhantomData
+        }
+    }
+
+    #[inline(always)]
+    fn advance(&mut self) -> Option<usize> {
+        let current: usize = self.next?;
+        self.next = O::next(current, self.root, self.length);
+
+        Some(current)
+    }
+}
+
+impl<O: TraversalOrder> Iterator for Traversal<(), O> {
+    type Item = Step<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.advance().map(| index | Step::new(index, index))
+    }
+}
+
+impl<'a, C, O> Iterator for Traversal<&'a C, O> where
+    C: ?Sized + IndexedCollection,
+    C::Output: Sized,
+    O: TraversalOrder
+{
+    type Item = Step<&'a C::Output>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let source: &'a C = self.source;
+        self.advance().map(| index | Step::new(index, &source[index]))
+    }
+}
+
+// Mutable traversals lend one node at a time rather than iterating - an
+// `IndexMut` impl is free to map two indices onto the same node
+impl<C, O> Traversal<&mut C, O> where
+    C: ?Sized + IndexedCollectionMut,
+    C::Output: Sized,
+    O: TraversalOrder
+{
+    pub fn next_mut(&mut self) -> Option<Step<&mut C::Output>> {
+        let index: usize = self.advance()?;
+
+        Some(Step::new(index, &mut self.source[index]))
+    }
+
+    pub fn for_each_mut<F>(mut self, mut f: F) where
+        F: FnMut(Step<&mut C::Output>)
+    {
+        while let Some(step) = self.next_mut() {
+            f(step);
+        }
+    }
+}
+
+impl<O: TraversalOrder> FusedIterator for Traversal<(), O> {}
+impl<C, O> FusedIterator for Traversal<&C, O> where
+    C: ?Sized + IndexedCollection,
+    C::Output: Sized,
+    O: TraversalOrder {}
+
+/*################################
+          Fenwick Paths
+################################*/
+
+// Update paths climb to every node whose range covers the start index
+#[derive(Debug)]
+pub struct UpdatePath<S> {
+    source: S,
+    next: usize,
+    length: usize
+}
+
+// Query paths descend through the disjoint ranges composing [1, start]
+#[derive(Debug)]
+pub struct QueryPath<S> {
+    source: S,
+    next: usize
+}
+
+impl<S> UpdatePath<S> {
+    pub(crate) fn new(source: S, start: usize, length: usize) -> Self {
+        Self { source, next: start, length }
+    }
+
+    #[inline(always)]
+    fn advance(&mut self) -> Option<usize> {
+        let current: usize = self.next;
+        if current == 0 || current >= self.length {
+            return None;
+        }
+
+        self.next = current.checked_add(lsb(current)).unwrap_or(0);
+        Some(current)
+    }
+}
+
+impl<S> QueryPath<S> {
+    pub(crate) fn new(source: S, start: usize, length: usize) -> Self {
+        Self { source, next: if start < length { start } else { 0 } }
+    }
+
+    #[inline(always)]
+    fn advance(&mut self) -> Option<usize> {
+        let current: usize = self.next;
+        if current == 0 {
+            return None;
+        }
+
+        self.next = current - lsb(current);
+        Some(current)
+    }
+}
+
+macro_rules! impl_path_iter {
+    ($path:ident) => {
+        impl Iterator for $path<()> {
+            type Item = usize;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.advance()
+            }
+        }
+
+        impl<'a, C> Iterator for $path<&'a C> where
+            C: ?Sized + IndexedCollection,
+            C::Output: Sized
+        {
+            type Item = &'a C::Output;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                let source: &'a C = self.source;
+                self.advance().map(| index | &source[index])
+            }
+        }
+
+        // Lends one node at a time, as mutable traversals do
+        impl<C> $path<&mut C> where
+            C: ?Sized + IndexedCollectionMut,
+            C::Output: Sized
+        {
+            pub fn next_mut(&mut self) -> Option<&mut C::Output> {
+                let index: usize = self.advance()?;
+
+                Some(&mut self.source[index])
+            }
+
+            pub fn for_each_mut<F>(mut self, mut f: F) where
+                F: FnMut(&mut C::Output)
+            {
+                while let Some(node) = self.next_mut() {
+                    f(node);
+                }
+            }
+        }
+
+        impl FusedIterator for $path<()> {}
+        impl<C> FusedIterator for $path<&C> where
+            C: ?Sized + IndexedCollection,
+            C::Output: Sized {}
+    };
+}
+
+impl_path_iter!{UpdatePath}
+impl_path_iter!{QueryPath}
+
+/*################################
+           Walker Impls
+################################*/
+
+macro_rules! impl_orders {
+    ($generic:ident() -> $source:ty, $($name:ident: $order:ty),+) => {
+        $(
+            pub fn $name(&self) -> Traversal<$source, $order> {
+                self.$generic::<$order>()
+            }
+        )+
+    };
+    ($generic:ident(mut) -> $source:ty, $($name:ident: $order:ty),+) => {
+        $(
+            pub fn $name(&mut self) -> Traversal<$source, $order> {
+                self.$generic::<$order>()
+            }
+        )+
+    };
+}
+
+// Steps and paths always yield usize collection indices, whatever the walker's width
+
+impl<I: Index> IndexView<I> {
+    pub fn update_path(&self, length: usize) -> UpdatePath<()> {
+        UpdatePath::new((), self.index.as_usize(), length)
+    }
+
+    pub fn query_path(&self) -> QueryPath<()> {
+        QueryPath::new((), self.index.as_usize(), usize::MAX)
+    }
+}
+
+impl<I: Index> VirtualTreeView<I> {
+    pub fn traversal<O: TraversalOrder>(&self) -> Traversal<(), O> {
+        Traversal::new((), self.curr.index.as_usize(), self.length())
+    }
+
+    pub fn update_path(&self, index: I) -> UpdatePath<()> {
+        UpdatePath::new((), index.as_usize(), self.length())
+    }
+
+    pub fn query_path(&self, index: I) -> QueryPath<()> {
+        QueryPath::new((), index.as_usize(), self.length())
+    }
+
+    impl_orders!{
+        traversal() -> (),
+        pre_order: PreOrder, in_order: InOrder,
+        post_order: PostOrder, level_order: LevelOrder
+    }
+}
+
+impl<'a, C, I> StatefulTreeView<'a, C, I> where
+    C: ?Sized + IndexedCollection,
+    C::Output: Sized,
+    I: Index
+{
+    pub fn traversal<O: TraversalOrder>(&self) -> Traversal<&'a C, O> {
+        Traversal::new(self.collection, self.curr.index.as_usize(), self.collection.length())
+    }
+
+    pub fn update_path(&self, index: I) -> UpdatePath<&'a C> {
+        UpdatePath::new(self.collection, index.as_usize(), self.collection.length())
+    }
+
+    pub fn query_path(&self, index: I) -> QueryPath<&'a C> {
+        QueryPath::new(self.collection, index.as_usize(), self.collection.length())
+    }
+
+    impl_orders!{
+        traversal() -> &'a C,
+        pre_order: PreOrder, in_order: InOrder,
+        post_order: PostOrder, level_order: LevelOrder
+    }
+}
+
+impl<'a, C, I> StatefulTreeViewMut<'a, C, I> where
+    C: ?Sized + IndexedCollectionMut,
+    C::Output: Sized,
+    I: Index
+{
+    pub fn traversal<O: TraversalOrder>(&self) -> Traversal<&C, O> {
+        Traversal::new(&*self.collection, self.curr.index.as_usize(), self.collection.length())
+    }
+
+    pub fn traversal_mut<O: TraversalOrder>(&mut self) -> Traversal<&mut C, O> {
+        let length: usize = self.collection.length();
+        Traversal::new(&mut *self.collection, self.curr.index.as_usize(), length)
+    }
+
+    pub fn update_path(&self, index: I) -> UpdatePath<&C> {
+        UpdatePath::new(&*self.collection, index.as_usize(), self.collection.length())
+    }
+
+    pub fn update_path_mut(&mut self, index: I) -> UpdatePath<&mut C> {
+        let length: usize = self.collection.length();
+        UpdatePath::new(&mut *self.collection, index.as_usize(), length)
+    }
+
+    pub fn query_path(&self, index: I) -> QueryPath<&C> {
+        QueryPath::new(&*self.collection, index.as_usize(), self.collection.length())
+    }
+
+    pub fn query_path_mut(&mut self, index: I) -> QueryPath<&mut C> {
+        let length: usize = self.collection.length();
+        QueryPath::new(&mut *self.collection, index.as_usize(), length)
+    }
+
+    impl_orders!{
+        traversal() -> &C,
+        pre_order: PreOrder, in_order: InOrder,
+        post_order: PostOrder, level_order: LevelOrder
+    }
+
+    impl_orders!{
+        traversal_mut(mut) -> &mut C,
+        pre_order_mut: PreOrder, in_order_mut: InOrder,
+        post_order_mut: PostOrder, level_order_mut: LevelOrder
+    }
+}
@@ -1,5 +1,6 @@
 pub mod macros;
 pub mod fenwick;
+pub mod dary;
 
 pub use tree::*;
 
@@ -8,24 +9,178 @@ pub use tree::*;
 ################################*/
 
 pub mod tree {
+    use core::borrow::Borrow;
+    use core::cmp::Ordering;
+    use core::marker::PhantomData;
+    use core::ops::{Range, RangeBounds};
+
     pub trait Height {
         fn height(&self) -> usize;
     }
 
+    // Extracts the key a tree orders its nodes by
+    pub trait NodeKey<T> {
+        type Key: ?Sized;
+
+        fn key(node: &T) -> &Self::Key;
+    }
+
+    // Sets order nodes by the nodes themselves...
+    #[derive(Debug, Default, Clone, Copy, PartialEq)]
+    pub struct Identity;
+
+    // ...while maps order their (K, V) entries by K alone
+    #[derive(Debug, Default, Clone, Copy, PartialEq)]
+    pub struct EntryKey;
+
+    impl<T> NodeKey<T> for Identity {
+        type Key = T;
+
+        fn key(node: &T) -> &T {
+            node
+        }
+    }
+
+    impl<K, V> NodeKey<(K, V)> for EntryKey {
+        type Key = K;
+
+        fn key(node: &(K, V)) -> &K {
+            &node.0
+        }
+    }
+
+    // Orders keys for a tree, in place of their `PartialOrd` impl. `R` lets
+    // walkers compare a probe against nodes of another type
+    pub trait Compare<L: ?Sized, R: ?Sized = L> {
+        fn compare(lhs: &L, rhs: &R) -> Option<Ordering>;
+    }
+
+    #[derive(Debug, Default, Clone, Copy, PartialEq)]
+    pub struct Natural;
+
+    #[derive(Debug, Default, Clone, Copy, PartialEq)]
+    pub struct Reverse<O = Natural>(PhantomData<O>);
+
+    // Orders by a projected field - `P` picks the field, `O` orders it
+    #[derive(Debug, Default, Clone, Copy, PartialEq)]
+    pub struct ByKey<P, O = Natural>(PhantomData<(P, O)>);
+
+    // IEEE 754 totalOrder - NaNs sort after infinity, and -0.0 before 0.0
+    #[derive(Debug, Default, Clone, Copy, PartialEq)]
+    pub struct TotalOrder;
+
+    impl<L: PartialOrd<R> + ?Sized, R: ?Sized> Compare<L, R> for Natural {
+        fn compare(lhs: &L, rhs: &R) -> Option<Ordering> {
+            lhs.partial_cmp(rhs)
+        }
+    }
+
+    impl<L: ?Sized, R: ?Sized, O: Compare<L, R>> Compare<L, R> for Reverse<O> {
+        fn compare(lhs: &L, rhs: &R) -> Option<Ordering> {
+            O::compare(lhs, rhs).map(Ordering::reverse)
+        }
+    }
+
+    impl<T, P, O> Compare<T> for ByKey<P, O> where
+        P: NodeKey<T>,
+        O: Compare<P::Key>
+    {
+        fn compare(lhs: &T, rhs: &T) -> Option<Ordering> {
+            O::compare(P::key(lhs), P::key(rhs))
+        }
+    }
+
+    macro_rules! impl_total_order {
+        ($($float:ty),*) => {
+            $(
+                impl Compare<$float> for TotalOrder {
+                    fn compare(lhs: &$float, rhs: &$float) -> Option<Ordering> {
+                        Some(lhs.total_cmp(rhs))
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_total_order!{f32, f64}
+
+    // Float keys ordered by `total_cmp`, for trees that order by `PartialOrd`
+    macro_rules! impl_total_float {
+        ($($name:ident($float:ty)),*) => {
+            $(
+                #[derive(Debug, Default, Clone, Copy)]
+                pub struct $name(pub $float);
+
+                impl PartialEq for $name {
+                    fn eq(&self, other: &Self) -> bool {
+                        self.cmp(other) == Ordering::Equal
+                    }
+                }
+
+                impl Eq for $name {}
+
+                impl PartialOrd for $name {
+                    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                        Some(self.cmp(other))
+                    }
+                }
+
+                impl Ord for $name {
+                    fn cmp(&self, other: &Self) -> Ordering {
+                        self.0.total_cmp(&other.0)
+                    }
+                }
+
+                impl From<$float> for $name {
+                    fn from(value: $float) -> Self {
+                        Self(value)
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_total_float!{TotalF32(f32), TotalF64(f64)}
+
+    // Lookups accept anything the node key borrows as, e.g. `&str` for `String` keys
     pub trait TreeRead {
         type Node;
+        type Key: ?Sized;
+        type Order: Compare<Self::Key>;
         type Error;
     
-        fn get(&self, node: &Self::Node) -> Result<&Self::Node, Self::Error>;        
+        fn get<Q: ?Sized>(&self, key: &Q) -> Result<&Self::Node, Self::Error> where
+            Self::Key: Borrow<Q>,
+            Self::Order: Compare<Q>;
         fn first(&self) -> Result<&Self::Node, Self::Error>;
         fn last(&self) -> Result<&Self::Node, Self::Error>;
         fn root(&self) -> Result<&Self::Node, Self::Error>;
 
-        fn contains(&self, node: &Self::Node) -> Result<bool, Self::Error>;
+        fn contains<Q: ?Sized>(&self, key: &Q) -> Result<bool, Self::Error> where
+            Self::Key: Borrow<Q>,
+            Self::Order: Compare<Q>;
+    }
+
+    // Nearest-neighbour lookups - `lower` and `higher` exclude the key itself
+    pub trait TreeReadOrdered: TreeRead {
+        fn floor<Q: ?Sized>(&self, key: &Q) -> Result<&Self::Node, Self::Error> where
+            Self::Key: Borrow<Q>,
+            Self::Order: Compare<Q>;
+        fn ceiling<Q: ?Sized>(&self, key: &Q) -> Result<&Self::Node, Self::Error> where
+            Self::Key: Borrow<Q>,
+            Self::Order: Compare<Q>;
+        fn lower<Q: ?Sized>(&self, key: &Q) -> Result<&Self::Node, Self::Error> where
+            Self::Key: Borrow<Q>,
+            Self::Order: Compare<Q>;
+        fn higher<Q: ?Sized>(&self, key: &Q) -> Result<&Self::Node, Self::Error> where
+            Self::Key: Borrow<Q>,
+            Self::Order: Compare<Q>;
     }
 
     pub trait TreeReadMut: TreeRead {
-        fn get_mut(&mut self, node: &Self::Node) -> Result<&mut Self::Node, Self::Error>;
+        fn get_mut<Q: ?Sized>(&mut self, key: &Q) -> Result<&mut Self::Node, Self::Error> where
+            Self::Key: Borrow<Q>,
+            Self::Order: Compare<Q>;
 
         fn first_mut(&mut self) -> Result<&mut Self::Node, Self::Error>;
         fn last_mut(&mut self) -> Result<&mut Self::Node, Self::Error>;
@@ -34,187 +189,447 @@ pub mod tree {
 
     pub trait TreeWrite: TreeReadMut {
         fn insert(&mut self, node: Self::Node) -> Result<Option<Self::Node>, Self::Error>;
-        fn delete(&mut self, node: &Self::Node) -> Result<Self::Node, Self::Error>;
+        fn delete<Q: ?Sized>(&mut self, key: &Q) -> Result<Self::Node, Self::Error> where
+            Self::Key: Borrow<Q>,
+            Self::Order: Compare<Q>;
 
         fn pop(&mut self) -> Result<Self::Node, Self::Error>;
     }
+
+    // Trees keep their nodes in key order, so iteration is a walk over that slice
+    pub trait TreeIter: TreeRead {
+        fn as_slice(&self) -> &[Self::Node];
+
+        fn iter(&self) -> core::slice::Iter<'_, Self::Node> {
+            self.as_slice().iter()
+        }
+    }
+
+    // Order statistics - positions are 0-based over the sorted nodes
+    pub trait TreeRank: TreeIter {
+        // Number of nodes ordered before `key`
+        fn rank<Q: ?Sized>(&self, key: &Q) -> Result<usize, Self::Error> where
+            Self::Key: Borrow<Q>,
+            Self::Order: Compare<Q>;
+        fn index_of<Q: ?Sized>(&self, key: &Q) -> Result<Option<usize>, Self::Error> where
+            Self::Key: Borrow<Q>,
+            Self::Order: Compare<Q>;
+
+        fn select(&self, n: usize) -> Option<&Self::Node> {
+            self.as_slice().get(n)
+        }
+
+        // Alias of `select`, for callers used to indexed maps
+        fn get_index(&self, n: usize) -> Option<&Self::Node> {
+            self.select(n)
+        }
+
+        // Lower median for even lengths
+        fn median(&self) -> Option<&Self::Node> {
+            self.select(self.as_slice().len().checked_sub(1)? / 2)
+        }
+
+        // Nearest-rank percentile, `p` in 0..=100
+        fn percentile(&self, p: f64) -> Option<&Self::Node> {
+            let length: usize = self.as_slice().len();
+            let rank: f64 = (p.clamp(0.0, 100.0) / 100.0 * length as f64).ceil();
+
+            self.select((rank as usize).max(1) - 1)
+        }
+    }
+
+    pub trait TreeRankWrite: TreeRank + TreeWrite {
+        fn remove_index(&mut self, n: usize) -> Option<Self::Node>;
+    }
+
+    // Ranges cover a contiguous run of the sorted slice, so
+    // only their two ends need to be located
+    pub trait TreeRange: TreeIter {
+        fn range_span<Q, R>(&self, range: R) -> Result<Range<usize>, Self::Error> where
+            Self::Key: Borrow<Q>,
+            Self::Order: Compare<Q>,
+            Q: ?Sized,
+            R: RangeBounds<Q>;
+
+        fn range<Q, R>(&self, range: R) -> Result<core::slice::Iter<'_, Self::Node>, Self::Error> where
+            Self::Key: Borrow<Q>,
+            Self::Order: Compare<Q>,
+            Q: ?Sized,
+            R: RangeBounds<Q>
+        {
+            let span: Range<usize> = self.range_span(range)?;
+            Ok(self.as_slice()[span].iter())
+        }
+
+        fn count_range<Q, R>(&self, range: R) -> Result<usize, Self::Error> where
+            Self::Key: Borrow<Q>,
+            Self::Order: Compare<Q>,
+            Q: ?Sized,
+            R: RangeBounds<Q>
+        {
+            Ok(self.range_span(range)?.len())
+        }
+    }
+
 }
 
 /*################################
             Tree KV
 ################################*/
 
+// Maps are trees of (K, V) entries, ordered by K. These traits
+// project the tree API onto keys and values
 pub mod tree_kv {
-    use core::cmp::Ordering;
+    use core::borrow::Borrow;
+    use core::iter::FusedIterator;
+    use core::ops::{Range, RangeBounds};
+    use core::slice;
     use super::tree::{
-        TreeRead, TreeReadMut,
-        TreeWrite
+        Compare,
+        TreeRead, TreeReadMut, TreeReadOrdered,
+        TreeWrite, TreeIter,
+        TreeRange, TreeRank, TreeRankWrite
     };
 
-    fn construct_search<'c, 't, K: 't, V>(key: &'c K) -> NodeKV<'t, K, V> {
-        unsafe {
-            std::mem::transmute::<NodeKV::<'c, K, V>, NodeKV::<'t, K, V>>(
-                NodeKV::<'c, K, V>::Search(key)
-            )
-        }
+    #[inline(always)]
+    fn pair<K, V>(entry: &(K, V)) -> (&K, &V) {
+        (&entry.0, &entry.1)
     }
 
-    #[derive(Debug, Clone)]
-    pub enum NodeKV<'a, K, V> {
-        Occupied(K, V),
-        Search(&'a K),
-        None
+    #[inline(always)]
+    fn pair_mut<K, V>(entry: &mut (K, V)) -> (&K, &mut V) {
+        (&entry.0, &mut entry.1)
     }
 
-    impl<'a, K: PartialEq, V> Default for NodeKV<'a, K, V> {
-        fn default() -> Self {
-            NodeKV::None
+    pub trait TreeReadKV<K, V, E> where
+        Self: TreeRead<Node = (K, V), Key = K, Error = E>
+    {
+        fn get<'a, Q: ?Sized>(&'a self, key: &Q) -> Result<&'a V, E> where
+            K: Borrow<Q> + 'a,
+            V: 'a,
+            Self::Order: Compare<Q>
+        {
+            Ok(&TreeRead::get(self, key)?.1)
         }
-    }
 
-    impl<'a, K: PartialEq, V> NodeKV<'a, K, V> {
-        pub fn unwrap(self: Self) -> V {
-            match self {
-                NodeKV::Occupied(_, v) => v,
-                _ => panic!("Invalid tree NodeKV returned from search")
-            }
+        fn get_key_value<'a, Q: ?Sized>(&'a self, key: &Q) -> Result<(&'a K, &'a V), E> where
+            K: Borrow<Q> + 'a,
+            V: 'a,
+            Self::Order: Compare<Q>
+        {
+            Ok(pair(TreeRead::get(self, key)?))
         }
 
-        pub fn inner(self: &Self) -> &V {
-            match self {
-                NodeKV::Occupied(_, v) => v,
-                _ => panic!("Invalid tree NodeKV returned from search")
-            }
+        fn first<'a>(&'a self) -> Result<&'a V, E> where K: 'a, V: 'a {
+            Ok(&TreeRead::first(self)?.1)
         }
 
-        pub fn inner_mut(self: &mut Self) -> &mut V {
-            match self {
-                NodeKV::Occupied(_, v) => v,
-                _ => panic!("Invalid tree NodeKV returned from search")
-            }
+        fn last<'a>(&'a self) -> Result<&'a V, E> where K: 'a, V: 'a {
+            Ok(&TreeRead::last(self)?.1)
+        }
+
+        fn root<'a>(&'a self) -> Result<&'a V, E> where K: 'a, V: 'a {
+            Ok(&TreeRead::root(self)?.1)
+        }
+
+        fn contains<Q: ?Sized>(&self, key: &Q) -> Result<bool, E> where
+            K: Borrow<Q>,
+            Self::Order: Compare<Q>
+        {
+            TreeRead::contains(self, key)
+        }
+
+        fn floor<'a, Q: ?Sized>(&'a self, key: &Q) -> Result<(&'a K, &'a V), E> where
+            Self: TreeReadOrdered,
+            Self::Order: Compare<Q>,
+            K: Borrow<Q> + 'a,
+            V: 'a
+        {
+            Ok(pair(TreeReadOrdered::floor(self, key)?))
+        }
+
+        fn ceiling<'a, Q: ?Sized>(&'a self, key: &Q) -> Result<(&'a K, &'a V), E> where
+            Self: TreeReadOrdered,
+            Self::Order: Compare<Q>,
+            K: Borrow<Q> + 'a,
+            V: 'a
+        {
+            Ok(pair(TreeReadOrdered::ceiling(self, key)?))
+        }
+
+        fn lower<'a, Q: ?Sized>(&'a self, key: &Q) -> Result<(&'a K, &'a V), E> where
+            Self: TreeReadOrdered,
+            Self::Order: Compare<Q>,
+            K: Borrow<Q> + 'a,
+            V: 'a
+        {
+            Ok(pair(TreeReadOrdered::lower(self, key)?))
+        }
+
+        fn higher<'a, Q: ?Sized>(&'a self, key: &Q) -> Result<(&'a K, &'a V), E> where
+            Self: TreeReadOrdered,
+            Self::Order: Compare<Q>,
+            K: Borrow<Q> + 'a,
+            V: 'a
+        {
+            Ok(pair(TreeReadOrdered::higher(self, key)?))
         }
     }
 
-    impl<'a, K, V> PartialEq<K> for NodeKV<'a, K, V> where
-        K: PartialEq
+    pub trait TreeReadKVMut<K, V, E> where
+        Self: TreeReadMut<Node = (K, V), Key = K, Error = E>
     {
-        fn eq(&self, other: &K) -> bool {
-            match self {
-                NodeKV::Occupied(k, _) => other.eq(k),
-                NodeKV::Search(k) => other.eq(*k),
-                NodeKV::None => false
-            }
+        fn get_mut<'a, Q: ?Sized>(&'a mut self, key: &Q) -> Result<&'a mut V, E> where
+            K: Borrow<Q> + 'a,
+            V: 'a,
+            Self::Order: Compare<Q>
+        {
+            Ok(&mut TreeReadMut::get_mut(self, key)?.1)
+        }
+
+        fn first_mut<'a>(&'a mut self) -> Result<&'a mut V, E> where K: 'a, V: 'a {
+            Ok(&mut TreeReadMut::first_mut(self)?.1)
+        }
+
+        fn last_mut<'a>(&'a mut self) -> Result<&'a mut V, E> where K: 'a, V: 'a {
+            Ok(&mut TreeReadMut::last_mut(self)?.1)
+        }
+
+        fn root_mut<'a>(&'a mut self) -> Result<&'a mut V, E> where K: 'a, V: 'a {
+            Ok(&mut TreeReadMut::root_mut(self)?.1)
         }
     }
 
-    impl<'a, K, V> PartialEq for NodeKV<'a, K, V> where
-        K: PartialEq
+    pub trait TreeWriteKV<K, V, E> where
+        Self: TreeWrite<Node = (K, V), Key = K, Error = E>
     {
-        fn eq(&self, other: &Self) -> bool {
-            match self {
-                NodeKV::Occupied(k, _) => other.eq(k),
-                NodeKV::Search(k) => other.eq(*k),
-                NodeKV::None => false
-            }
+        fn insert(&mut self, key: K, value: V) -> Result<Option<V>, E> {
+            Ok(TreeWrite::insert(self, (key, value))?.map(| (_, value) | value))
+        }
+
+        fn delete<Q: ?Sized>(&mut self, key: &Q) -> Result<V, E> where
+            K: Borrow<Q>,
+            Self::Order: Compare<Q>
+        {
+            Ok(TreeWrite::delete(self, key)?.1)
+        }
+
+        fn pop(&mut self) -> Result<(K, V), E> {
+            TreeWrite::pop(self)
         }
     }
 
-    impl<'a, K, V> PartialOrd<K> for NodeKV<'a, K, V> where
-        K: PartialOrd
+    pub trait TreeIterKV<K, V, E> where
+        Self: TreeIter<Node = (K, V), Key = K, Error = E>
     {
-        fn partial_cmp(&self, other: &K) -> Option<Ordering> {
-            match self {
-                NodeKV::Occupied(k, _) => other.partial_cmp(k),
-                NodeKV::Search(k) => other.partial_cmp(*k),
-                NodeKV::None => None
-            }
+        fn iter<'a>(&'a self) -> Iter<'a, K, V> where K: 'a, V: 'a {
+            Iter { inner: TreeIter::iter(self) }
+        }
+
+        fn keys<'a>(&'a self) -> Keys<'a, K, V> where K: 'a, V: 'a {
+            Keys { inner: TreeIter::iter(self) }
+        }
+
+        fn values<'a>(&'a self) -> Values<'a, K, V> where K: 'a, V: 'a {
+            Values { inner: TreeIter::iter(self) }
+        }
+
+        fn into_keys(self) -> IntoKeys<Self::IntoIter> where
+            Self: Sized + IntoIterator<Item = (K, V)>
+        {
+            IntoKeys { inner: self.into_iter() }
+        }
+
+        fn into_values(self) -> IntoValues<Self::IntoIter> where
+            Self: Sized + IntoIterator<Item = (K, V)>
+        {
+            IntoValues { inner: self.into_iter() }
         }
     }
 
-    impl<'a, K, V> PartialOrd for NodeKV<'a, K, V> where
-        K: PartialOrd
+    // Only values are ever lent out mutably - a key changed in
+    // place could silently break the order of the tree
+    pub trait TreeIterKVMut<K, V, E> where
+        Self: TreeIter<Node = (K, V), Key = K, Error = E>
     {
-        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-            match self {
-                NodeKV::Occupied(k, _) => other.partial_cmp(k),
-                NodeKV::Search(k) => other.partial_cmp(*k),
-                NodeKV::None => None
-            }
+        fn iter_mut<'a>(&'a mut self) -> IterMut<'a, K, V> where K: 'a, V: 'a;
+
+        fn values_mut<'a>(&'a mut self) -> ValuesMut<'a, K, V> where K: 'a, V: 'a {
+            ValuesMut { inner: self.iter_mut().inner }
         }
     }
 
-    pub trait TreeReadKV<'t, K, V, E> where
-        Self: TreeRead<Node = NodeKV<'t, K, V>, Error = E>,
-        K: PartialEq + 't
+    pub trait TreeRankKV<K, V, E> where
+        Self: TreeRank<Node = (K, V), Key = K, Error = E>
     {
-        fn get<'c>(&'c self, key: &'c K) -> Result<&V, E> where 't: 'c {
-            Ok(TreeRead::get(self, &construct_search(key))?.inner())
+        fn rank<Q: ?Sized>(&self, key: &Q) -> Result<usize, E> where
+            K: Borrow<Q>,
+            Self::Order: Compare<Q>
+        {
+            TreeRank::rank(self, key)
         }
 
-        fn first<'c>(&'c self) -> Result<&V, Self::Error> where 't: 'c {
-            Ok(TreeRead::first(self)?.inner())
+        fn select<'a>(&'a self, n: usize) -> Option<(&'a K, &'a V)> where K: 'a, V: 'a {
+            TreeRank::select(self, n).map(pair)
         }
 
-        fn last<'c>(&'c self) -> Result<&V, Self::Error> where 't: 'c {
-            Ok(TreeRead::last(self)?.inner())
+        fn get_index<'a>(&'a self, n: usize) -> Option<(&'a K, &'a V)> where K: 'a, V: 'a {
+            TreeRank::get_index(self, n).map(pair)
         }
 
-        fn root<'c>(&'c self) -> Result<&V, Self::Error> where 't: 'c {
-            Ok(TreeRead::root(self)?.inner())
+        fn index_of<Q: ?Sized>(&self, key: &Q) -> Result<Option<usize>, E> where
+            K: Borrow<Q>,
+            Self::Order: Compare<Q>
+        {
+            TreeRank::index_of(self, key)
         }
 
-        fn contains<'c>(&'t self, key: &'c K) -> Result<bool, E> {
-            TreeRead::contains(self, &construct_search(key))
+        fn median<'a>(&'a self) -> Option<(&'a K, &'a V)> where K: 'a, V: 'a {
+            TreeRank::median(self).map(pair)
         }
-    }
 
-    pub trait TreeReadKVMut<'t, K, V, E> where
-        Self: TreeReadMut<Node = NodeKV<'t, K, V>>,
-        K: PartialEq + 't
-    {
-        fn get_mut<'c>(&'c mut self, key: &'c K) -> Result<&mut V, Self::Error> where 't: 'c {
-            Ok(TreeReadMut::get_mut(self, &construct_search(key))?.inner_mut())
+        fn percentile<'a>(&'a self, p: f64) -> Option<(&'a K, &'a V)> where K: 'a, V: 'a {
+            TreeRank::percentile(self, p).map(pair)
         }
+    }
 
-        fn first_mut<'c>(&'c mut self) -> Result<&mut V, Self::Error> where 't: 'c {
-            Ok(TreeReadMut::first_mut(self)?.inner_mut())
+    pub trait TreeRankKVWrite<K, V, E> where
+        Self: TreeRankWrite<Node = (K, V), Key = K, Error = E>
+    {
+        fn remove_index(&mut self, n: usize) -> Option<(K, V)> {
+            TreeRankWrite::remove_index(self, n)
         }
+    }
 
-        fn last_mut<'c>(&'c mut self) -> Result<&mut V, Self::Error> where 't: 'c {
-            Ok(TreeReadMut::last_mut(self)?.inner_mut())
+    pub trait TreeRangeKV<K, V, E> where
+        Self: TreeRange<Node = (K, V), Key = K, Error = E>
+    {
+        fn range<'a, Q, R>(&'a self, range: R) -> Result<Iter<'a, K, V>, E> where
+            K: Borrow<Q> + 'a,
+            V: 'a,
+            Self::Order: Compare<Q>,
+            Q: ?Sized,
+            R: RangeBounds<Q>
+        {
+            let span: Range<usize> = TreeRange::range_span(self, range)?;
+            Ok(Iter { inner: self.as_slice()[span].iter() })
         }
 
-        fn root_mut<'c>(&'c mut self) -> Result<&mut V, Self::Error> where 't: 'c {
-            Ok(TreeReadMut::root_mut(self)?.inner_mut())
+        fn count_range<Q, R>(&self, range: R) -> Result<usize, E> where
+            K: Borrow<Q>,
+            Self::Order: Compare<Q>,
+            Q: ?Sized,
+            R: RangeBounds<Q>
+        {
+            TreeRange::count_range(self, range)
         }
     }
 
-    pub trait TreeWriteKV<'t, K, V, E> where
-        Self: TreeWrite<Node = NodeKV<'t, K, V>, Error = E>,
-        K: PartialEq + 't
+    // Values only, as with `iter_mut`
+    pub trait TreeRangeKVMut<K, V, E> where
+        Self: TreeRange<Node = (K, V), Key = K, Error = E> + TreeIterKVMut<K, V, E>
     {
-        fn insert(&mut self, key: K, value: V) -> Result<Option<V>, E> {
-            Ok(
-                TreeWrite::insert(self, NodeKV::Occupied(key, value))?
-                    .map(| kv  | kv.unwrap())
-            )
+        fn range_mut<'a, Q, R>(&'a mut self, range: R) -> Result<IterMut<'a, K, V>, E> where
+            K: Borrow<Q> + 'a,
+            V: 'a,
+            Self::Order: Compare<Q>,
+            Q: ?Sized,
+            R: RangeBounds<Q>
+        {
+            let span: Range<usize> = TreeRange::range_span(self, range)?;
+            Ok(IterMut { inner: TreeIterKVMut::iter_mut(self).inner.into_slice()[span].iter_mut() })
         }
+    }
 
-        fn delete<'c>(&mut self, key: &'c K) -> Result<V, E> {
-            Ok(TreeWrite::delete(self, &construct_search(key))?.unwrap())
-        }
+    impl<K, V, E, T> TreeReadKV<K, V, E> for T where
+        T: TreeRead<Node = (K, V), Key = K, Error = E> {}
+    impl<K, V, E, T> TreeReadKVMut<K, V, E> for T where
+        T: TreeReadMut<Node = (K, V), Key = K, Error = E> {}
+    impl<K, V, E, T> TreeWriteKV<K, V, E> for T where
+        T: TreeWrite<Node = (K, V), Key = K, Error = E> {}
+    impl<K, V, E, T> TreeIterKV<K, V, E> for T where
+        T: TreeIter<Node = (K, V), Key = K, Error = E> {}
+    impl<K, V, E, T> TreeRankKV<K, V, E> for T where
+        T: TreeRank<Node = (K, V), Key = K, Error = E> {}
+    impl<K, V, E, T> TreeRankKVWrite<K, V, E> for T where
+        T: TreeRankWrite<Node = (K, V), Key = K, Error = E> {}
+    impl<K, V, E, T> TreeRangeKV<K, V, E> for T where
+        T: TreeRange<Node = (K, V), Key = K, Error = E> {}
+    impl<K, V, E, T> TreeRangeKVMut<K, V, E> for T where
+        T: TreeRange<Node = (K, V), Key = K, Error = E> + TreeIterKVMut<K, V, E> {}
+
+    /*################################
+              KV Iterators
+    ################################*/
+
+    macro_rules! impl_kv_iter {
+        ($name:ident[$($ty_gen:tt)*], impl[$($impl_gen:tt)*], $inner:ty => $item:ty, $map:expr $(, bounded($bounded:ident))?) => {
+            pub struct $name<$($ty_gen)*> {
+                inner: $inner
+            }
 
-        fn pop<'c>(&'c mut self) -> Result<NodeKV<K, V>, E> where 't: 'c {
-            TreeWrite::pop(self)
+            impl<$($impl_gen)*> Iterator for $name<$($ty_gen)*> {
+                type Item = $item;
+
+                fn next(&mut self) -> Option<Self::Item> {
+                    self.inner.next().map($map)
+                }
+
+                fn size_hint(&self) -> (usize, Option<usize>) {
+                    self.inner.size_hint()
+                }
+            }
+
+            impl<$($impl_gen)*> DoubleEndedIterator for $name<$($ty_gen)*> where
+                $($bounded: DoubleEndedIterator)?
+            {
+                fn next_back(&mut self) -> Option<Self::Item> {
+                    self.inner.next_back().map($map)
+                }
+            }
+
+            impl<$($impl_gen)*> ExactSizeIterator for $name<$($ty_gen)*> where
+                $($bounded: ExactSizeIterator)? {}
+
+            impl<$($impl_gen)*> FusedIterator for $name<$($ty_gen)*> where
+                $($bounded: FusedIterator)? {}
+        };
+    }
+
+    impl_kv_iter!{
+        Iter['a, K, V], impl['a, K, V],
+        slice::Iter<'a, (K, V)> => (&'a K, &'a V), pair
+    }
+    impl_kv_iter!{
+        IterMut['a, K, V], impl['a, K, V],
+        slice::IterMut<'a, (K, V)> => (&'a K, &'a mut V), pair_mut
+    }
+    impl<'a, K, V> From<&'a mut [(K, V)]> for IterMut<'a, K, V> {
+        fn from(entries: &'a mut [(K, V)]) -> Self {
+            Self { inner: entries.iter_mut() }
         }
     }
 
-    impl<'t, K: PartialEq + 't, V, E, T> TreeReadKV<'t, K, V, E> for T where
-        T: TreeRead<Node = NodeKV<'t, K, V>, Error = E> {}
-    impl<'t, K: PartialEq + 't, V, E, T> TreeReadKVMut<'t, K, V, E> for T where
-        T: TreeReadMut<Node = NodeKV<'t, K, V>, Error = E> {}
-    impl<'t, K: PartialEq + 't, V, E, T> TreeWriteKV<'t, K, V, E> for T where
-        T: TreeWrite<Node = NodeKV<'t, K, V>, Error = E> {}
+    impl_kv_iter!{
+        Keys['a, K, V], impl['a, K, V],
+        slice::Iter<'a, (K, V)> => &'a K, | (key, _) | key
+    }
+    impl_kv_iter!{
+        Values['a, K, V], impl['a, K, V],
+        slice::Iter<'a, (K, V)> => &'a V, | (_, value) | value
+    }
+    impl_kv_iter!{
+        ValuesMut['a, K, V], impl['a, K, V],
+        slice::IterMut<'a, (K, V)> => &'a mut V, | (_, value) | value
+    }
+    impl_kv_iter!{
+        IntoKeys[T], impl[K, V, T: Iterator<Item = (K, V)>],
+        T => K, | (key, _) | key, bounded(T)
+    }
+    impl_kv_iter!{
+        IntoValues[T], impl[K, V, T: Iterator<Item = (K, V)>],
+        T => V, | (_, value) | value, bounded(T)
+    }
 }
 
 /*################################
@@ -223,38 +638,43 @@ pub mod tree_kv {
 
 pub trait TreeWalker<'w> {
     type Path;
+    type Side;
     type Output;
     type Error;
 
-    fn peek(&'w self, direction: Direction) -> Result<Self::Output, Self::Error>;
-    fn probe(&'w self, path: Self::Path) -> Result<Self::Output, Self::Error>;
+    fn peek(&'w self, direction: Direction<Self::Side>) -> Result<Self::Output, Self::Error>;
+    fn probe(&'w self, path: impl Into<Self::Path>) -> Result<Self::Output, Self::Error>;
     fn current(&'w self) -> Result<Self::Output, Self::Error>;
     fn sibling(&'w self) -> Result<Self::Output, Self::Error>;
 
-    fn traverse(&'w mut self, direction: Direction);
-    fn seek(&'w mut self, path: Self::Path);
+    fn traverse(&'w mut self, direction: Direction<Self::Side>);
+    fn seek(&'w mut self, path: impl Into<Self::Path>);
     fn reset(&'w mut self);
 
     fn node_type(&'w self) -> NodeType;
-    fn node_side(&'w self) -> NodeSide;
+    fn node_side(&'w self) -> Self::Side;
 }
 
 pub trait TreeWalkerMut<'w>: TreeWalker<'w> {
     type OutputMut;
 
-    fn peek_mut(&'w mut self, direction: Direction) -> Result<Self::OutputMut, Self::Error>;
-    fn probe_mut(&'w mut self, path: Self::Path) -> Result<Self::OutputMut, Self::Error>;
+    fn peek_mut(&'w mut self, direction: Direction<Self::Side>) -> Result<Self::OutputMut, Self::Error>;
+    fn probe_mut(&'w mut self, path: impl Into<Self::Path>) -> Result<Self::OutputMut, Self::Error>;
 
     fn current_mut(&'w mut self) -> Result<Self::OutputMut, Self::Error>;
     fn sibling_mut(&'w mut self) -> Result<Self::OutputMut, Self::Error>;
 }
 
+// Binary trees step down by side, wider trees by child ordinal
 #[repr(u8)]
-pub enum Direction {
+pub enum Direction<S = NodeSide> {
     Up,
-    Down(NodeSide),
+    Down(S),
     Left,
-    Right
+    Right,
+    // In-order neighbours
+    Next,
+    Prev
 }
 
 /*################################
@@ -273,4 +693,118 @@ pub enum NodeSide {
 pub enum NodeType {
     Node = 0,
     Leaf = 1
-}
\ No newline at end of file
+}
+
+/*################################
+            Tree Path
+################################*/
+
+// Steps are packed MSB-first: the step taken from the root
+// is the highest of the `depth` populated bits
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct TreePath {
+    pub(crate) steps: usize,
+    pub(crate) depth: usize
+}
+
+impl TreePath {
+    // One bit is reserved for the node marker when packed into an index
+    pub const MAX_DEPTH: usize = (usize::BITS - 1) as usize;
+
+    pub fn root() -> Self {
+        Self { steps: 0, depth: 0 }
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    pub fn is_root(&self) -> bool {
+        self.depth == 0
+    }
+
+    // Panics past `MAX_DEPTH` - `try_push` reports it instead
+    pub fn push(&mut self, side: NodeSide) {
+        if self.try_push(side).is_err() {
+            panic!("TreePath exceeded maximum depth of {}", Self::MAX_DEPTH);
+        }
+    }
+
+    pub fn try_push(&mut self, side: NodeSide) -> Result<(), fenwick::FenwickTreeError> {
+        require!(self.depth < Self::MAX_DEPTH, fenwick::FenwickTreeError::Overflow);
+
+        self.steps = self.steps << 1 | side as usize;
+        self.depth += 1;
+        Ok(())
+    }
+
+    pub fn pop(&mut self) -> Option<NodeSide> {
+        let side: NodeSide = self.last()?;
+
+        self.steps >>= 1;
+        self.depth -= 1;
+
+        Some(side)
+    }
+
+    pub fn last(&self) -> Option<NodeSide> {
+        match self.depth {
+            0 => None,
+            _ => Some(step_side(self.steps))
+        }
+    }
+
+    pub fn get(&self, step: usize) -> Option<NodeSide> {
+        let offset: usize = self.depth.checked_sub(step + 1)?;
+        Some(step_side(self.steps >> offset))
+    }
+
+    pub fn parent(&self) -> Option<Self> {
+        let mut parent: Self = *self;
+        parent.pop().map(|_| parent)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = NodeSide> + '_ {
+        (0..self.depth).filter_map(| step | self.get(step))
+    }
+
+    // Non-strict - a path is always a prefix of itself
+    pub fn is_prefix_of(&self, other: &Self) -> bool {
+        self.depth <= other.depth && other.steps >> (other.depth - self.depth) == self.steps
+    }
+
+    // Strict - a node is never its own ancestor
+    pub fn is_ancestor_of(&self, other: &Self) -> bool {
+        self.depth < other.depth && self.is_prefix_of(other)
+    }
+}
+
+#[inline(always)]
+fn step_side(steps: usize) -> NodeSide {
+    match steps & 1 {
+        0 => NodeSide::Left,
+        _ => NodeSide::Right
+    }
+}
+
+impl FromIterator<NodeSide> for TreePath {
+    fn from_iter<I: IntoIterator<Item = NodeSide>>(iter: I) -> Self {
+        let mut path: Self = Self::root();
+        for side in iter {
+            path.push(side);
+        }
+
+        path
+    }
+}
+
+impl core::fmt::Display for TreePath {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("/")?;
+        for side in self.iter() {
+            f.write_str(unwrap_enum!(side, NodeSide::Left => "L", NodeSide::Right => "R"))?;
+        }
+
+        Ok(())
+    }
+}
@@ -49,7 +49,7 @@ pub(crate) fn render_impl_insertable(parsed: ImplInsertable) -> TokenStream {
     let where_clause = parsed.where_clause;
     let name = parsed.name;
 
-    let (new, capacity) = match name.to_string().as_str() {
+    let (new, capacity, remaining) = match name.to_string().as_str() {
         "ArrayVec" | "SliceVec" => (
             quote!{
                 let out = #name::new();
@@ -59,6 +59,9 @@ pub(crate) fn render_impl_insertable(parsed: ImplInsertable) -> TokenStream {
             },
             quote!{
                 #name::capacity(self) > self.length()
+            },
+            quote!{
+                #name::capacity(self) - self.length()
             }
         ),
         _ => (
@@ -67,6 +70,9 @@ pub(crate) fn render_impl_insertable(parsed: ImplInsertable) -> TokenStream {
             },
             quote!{
                 #name::capacity(self) < usize::MAX
+            },
+            quote!{
+                usize::MAX - self.length()
             }
         )
     };
@@ -96,6 +102,18 @@ pub(crate) fn render_impl_insertable(parsed: ImplInsertable) -> TokenStream {
             fn has_capacity(&self) -> bool {
                 #capacity
             }
+
+            fn remaining_capacity(&self) -> usize {
+                #remaining
+            }
+
+            fn as_slice(&self) -> &[Self::Output] {
+                #name::as_slice(self)
+            }
+
+            fn as_mut_slice(&mut self) -> &mut [Self::Output] {
+                #name::as_mut_slice(self)
+            }
         }
     };
 